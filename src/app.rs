@@ -1,193 +1,2497 @@
 //! 앱 상태 관리 모듈
 
-use crate::jxa::{self, PlayerState, TrackInfo, SearchResult};
-use image::ImageReader;
+use crate::artwork::{self, ArtworkLoader};
+use crate::browser::{Browser, PlayRequest};
+use crate::command::Command;
+use crate::config::{Config, StartAction, VolumeTarget};
+use crate::discord::Presence;
+use crate::history::{self, ExportFormat};
+use crate::jxa::{self, AirPlayDevice, Chapter, EqState, LibraryStats, PlayerState, Playlist, QueueNext, RepeatMode, ResultSource, TrackInfo, SearchResult, UpNext};
+use crate::keymap::{KeyMap, KeyMapPreset};
+use crate::lyrics::Lyrics;
+use crate::poller::PlayerSnapshot;
+use crate::search::Searcher;
+use crate::skips::SkipLog;
+use crate::state::UiState;
+use crate::stats::SessionStats;
+use crate::theme::{BUILTIN_THEMES, Theme};
+use crate::track_loader::{RestOfAlbum, TrackData, TrackLoader};
+use ratatui::layout::Rect;
 use ratatui_image::{picker::Picker, protocol::StatefulProtocol};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::{Duration, Instant};
+
+/// 상태 메시지 표시 시간
+const STATUS_DURATION: Duration = Duration::from_secs(3);
+/// 가져올 아트워크 후보 개수
+const ARTWORK_CANDIDATES: usize = 5;
+/// 이전 챕터 이동 시 현재 챕터 처음으로 돌아가는 기준 (초)
+const CHAPTER_RESTART_THRESHOLD: f64 = 3.0;
+/// `,`/`.` 키 탐색 간격 (초)
+const SEEK_STEP: f64 = 10.0;
+/// `<`/`>` (Shift) 키 탐색 간격 (초)
+const SEEK_STEP_LONG: f64 = 30.0;
+/// 자동 재생 시 곡이 끝나서 정지했다고 판단하는 마지막 폴링 시점의 남은 시간 (초)
+const AUTOPLAY_END_THRESHOLD: f64 = 5.0;
+/// 자동 재생으로 한 번에 대기열에 넣을 곡 수
+const AUTOPLAY_TRACKS: usize = 25;
+/// 같은 목록 행을 이 시간 안에 두 번 클릭하면 더블 클릭으로 처리
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+/// 대체 파형 막대 개수
+const WAVEFORM_BARS: usize = 48;
+/// Up Next 목록에 표시할 최대 곡 수 (현재 곡 포함)
+const UP_NEXT_TRACKS: usize = 50;
+/// 입력을 멈춘 뒤 자동 검색까지 기다리는 시간
+const SEARCH_DEBOUNCE: Duration = Duration::from_millis(300);
+/// 자동 검색을 시작하는 최소 검색어 길이
+const LIVE_SEARCH_MIN_CHARS: usize = 2;
+/// 숫자 접두사 최댓값 (큰 수를 잘못 입력해도 반복 이동이 오래 걸리지 않도록)
+const MAX_PENDING_COUNT: usize = 9999;
+/// 검색 중 스피너 프레임
+const SPINNER_FRAMES: &str = "⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏";
+/// 스피너 프레임 간격
+const SPINNER_STEP: Duration = Duration::from_millis(80);
+/// 진행 바 음량 파형 구간 수 (화면 너비에 맞춰 다시 샘플링)
+const LOUDNESS_BARS: usize = 400;
+/// 긴 제목 마퀴가 한 글자 이동하는 간격
+const MARQUEE_STEP: Duration = Duration::from_millis(300);
+/// 아트워크 대신 그리는 막대 애니메이션이 한 프레임 진행하는 간격
+const SPECTRUM_STEP: Duration = Duration::from_millis(120);
+/// 트랙 정보를 연속으로 이만큼 읽지 못하면 Music.app이 종료된 것으로 판단
+const MUSIC_FAILURE_THRESHOLD: u32 = 3;
+/// `V`로 순환하는 볼륨 조절 단위
+const VOLUME_STEPS: [u8; 4] = [1, 2, 5, 10];
 
 /// 애플리케이션 모드
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub enum AppMode {
     #[default]
     Normal,
     SearchInput,
     SearchResults,
+    SearchFilter,
+    SkipLog,
+    /// AirPlay 출력 장치 목록 (그룹 구성 및 장치별 볼륨)
+    AirPlay,
+    /// 현재 세션 재생 통계
+    SessionStats,
+    /// 보관함 통계 (트랙 수, 전체 재생 시간, 플레이리스트 수)
+    LibraryStats,
+    /// 현재 곡 가사 (타임스탬프가 있으면 현재 줄 강조)
+    Lyrics,
+    /// 전체 화면 라이브러리 브라우저
+    Browser,
+    /// 다음에 재생될 곡 목록
+    UpNext,
+    /// 보관함 플레이리스트 목록
+    Playlists,
+    /// 이퀄라이저 프리셋 목록
+    Equalizer,
+    /// 명령 팔레트 (`:`로 열어 명령 이름 입력)
+    Command,
+    /// 예/아니오 확인 팝업 (y 입력 시 `on_confirm` 실행)
+    Confirm { message: String, on_confirm: Action },
+}
+
+/// 마우스 클릭 판정용으로 마지막에 렌더링한 목록 위치
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ListHitArea {
+    /// 테두리를 포함한 목록 영역
+    pub area: Rect,
+    /// 목록 스크롤 오프셋 (첫 번째로 보이는 항목 인덱스)
+    pub offset: usize,
+}
+
+/// 아트워크 캐시 키 (앨범 정보가 없으면 None)
+fn album_key(track: &TrackInfo) -> Option<String> {
+    if track.album.is_empty() {
+        return None;
+    }
+    Some(format!("{}\n{}", track.artist, track.album))
+}
+
+/// 별점 표시 문자열 (예: 3 → "★★★☆☆")
+pub fn stars_text(stars: u8) -> String {
+    let stars = stars.min(5) as usize;
+    format!("{}{}", "★".repeat(stars), "☆".repeat(5 - stars))
 }
 
+/// 화면 레이아웃
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LayoutMode {
+    /// 트랙 정보, 진행 바, 볼륨, 도움말을 모두 표시
+    #[default]
+    Full,
+    /// 아트워크를 크게 + 한 줄 캡션과 진행 바만 표시
+    Focus,
+    /// 상태 아이콘, 곡, 진행 시간, 볼륨만 한 줄로 표시 (작은 터미널/tmux 창용)
+    Compact,
+}
+
+/// 키 입력이나 확인 팝업으로 실행할 수 있는 동작
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    PlayPause,
+    PreviousTrack,
+    NextTrack,
+    PreviousChapter,
+    NextChapter,
+    SeekBackward,
+    SeekForward,
+    SeekBackwardLong,
+    SeekForwardLong,
+    /// 다음 숫자 키(0-9)로 0%-90% 위치 이동
+    SeekPercentPrefix,
+    VolumeUp,
+    VolumeDown,
+    /// 볼륨 1씩 미세 조절
+    VolumeUpFine,
+    VolumeDownFine,
+    Search,
+    CycleArtwork,
+    ToggleTitleNowPlaying,
+    ToggleShowRemaining,
+    ToggleVolumeTarget,
+    Duck,
+    ToggleMute,
+    /// 현재 트랙 별점 설정 (0이면 지우기)
+    Rate(u8),
+    ToggleLoved,
+    ToggleFocus,
+    ToggleCompact,
+    ToggleSlideshow,
+    CycleProfile,
+    CycleTheme,
+    CycleVolumeStep,
+    PauseAfterCurrent,
+    ToggleAutoplay,
+    ToggleShuffle,
+    CycleRepeat,
+    QueueRestOfAlbum,
+    SkipAndRemember,
+    OpenSkipLog,
+    OpenAirPlay,
+    OpenSessionStats,
+    OpenLibraryStats,
+    OpenLyrics,
+    OpenBrowser,
+    OpenUpNext,
+    OpenPlaylists,
+    OpenEqualizer,
+    /// 명령 팔레트 열기 (`shuffle`, `volume 80`, `seek 1:30` 등)
+    OpenCommand,
+    ExportHistory,
+    /// 전체 키 도움말 오버레이 열기/닫기
+    ToggleHelp,
+    /// 종료된 Music.app 다시 실행
+    RelaunchMusic,
+    /// 다음 폴링을 기다리지 않고 즉시 상태 갱신
+    Refresh,
+    /// 종료 요청 (`confirm_quit` 설정 시 확인)
+    Quit,
+    /// 확인 없이 즉시 종료
+    ForceQuit,
+    ClearSkipLog,
+}
+
+/// 결과를 기다리는 백그라운드 검색
+#[derive(Debug, Clone, Copy)]
+struct PendingSearch {
+    /// `Searcher::start`가 돌려준 요청 번호
+    id: u64,
+    /// 검색 시작 시각 (스피너 애니메이션용)
+    started: Instant,
+    /// 끝나면 결과 목록으로 이동 (Enter), 아니면 입력창 아래 미리 보기만 갱신
+    open_results: bool,
+}
 
 /// 검색 소스 모드
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SearchMode {
     #[default]
     Library,
     AppleMusic,
+    /// 보관함 + Apple Music 결과를 한 목록으로
+    Combined,
+}
+
+/// 검색 결과 필터 (클라이언트 측에서 계산)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResultFilter {
+    /// 재생 시간 범위 (초)
+    Duration { min: f64, max: f64 },
+    /// BPM 범위
+    Bpm { min: u32, max: u32 },
+}
+
+impl ResultFilter {
+    /// 필터 입력 파싱
+    /// - `2-4`, `2:30-4:15` → 재생 시간 범위 (분 또는 m:ss)
+    /// - `120-140bpm` → BPM 범위
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim().to_lowercase();
+        if let Some(range) = input.strip_suffix("bpm") {
+            let (min, max) = range.trim().split_once('-')?;
+            let (min, max) = (min.trim().parse().ok()?, max.trim().parse().ok()?);
+            return (min <= max).then_some(ResultFilter::Bpm { min, max });
+        }
+
+        let (min, max) = input.split_once('-')?;
+        let (min, max) = (parse_minutes(min)?, parse_minutes(max)?);
+        (min <= max).then_some(ResultFilter::Duration { min, max })
+    }
+
+    /// 검색 결과가 필터 조건에 맞는지 확인
+    pub fn matches(&self, result: &SearchResult) -> bool {
+        match *self {
+            ResultFilter::Duration { min, max } => result.duration >= min && result.duration <= max,
+            ResultFilter::Bpm { min, max } => result.bpm >= min && result.bpm <= max,
+        }
+    }
+
+    /// 팝업 제목에 표시할 필터 설명
+    pub fn label(&self) -> String {
+        match *self {
+            ResultFilter::Duration { min, max } => format!(
+                "{}:{:02}-{}:{:02}",
+                min as u64 / 60,
+                min as u64 % 60,
+                max as u64 / 60,
+                max as u64 % 60
+            ),
+            ResultFilter::Bpm { min, max } => format!("{}-{} BPM", min, max),
+        }
+    }
+}
+
+/// "4" 또는 "2:30" 형식의 분 단위 시간을 초로 변환
+fn parse_minutes(s: &str) -> Option<f64> {
+    let s = s.trim();
+    match s.split_once(':') {
+        Some((mins, secs)) => Some(mins.parse::<f64>().ok()? * 60.0 + secs.parse::<f64>().ok()?),
+        None => Some(s.parse::<f64>().ok()? * 60.0),
+    }
+}
+
+/// (이름, 아티스트, 앨범)이 같은 검색 결과를 하나로 합침
+/// 처음 나온 결과를 대표로 남기고 나머지 개수를 `duplicates`에 기록합니다.
+fn dedupe_results(results: Vec<SearchResult>) -> Vec<SearchResult> {
+    let mut deduped: Vec<SearchResult> = Vec::with_capacity(results.len());
+    for result in results {
+        let existing = deduped.iter_mut().find(|r| {
+            r.name.eq_ignore_ascii_case(&result.name)
+                && r.artist.eq_ignore_ascii_case(&result.artist)
+                && r.album.eq_ignore_ascii_case(&result.album)
+        });
+        match existing {
+            Some(existing) => existing.duplicates += 1,
+            None => deduped.push(result),
+        }
+    }
+    deduped
+}
+
+/// 트랙 이름/길이로 결정되는 장식용 파형 (막대 높이 0.0-1.0)
+/// 같은 곡은 항상 같은 모양이 되도록 FNV-1a 해시를 xorshift 시드로 사용합니다.
+fn waveform_heights(name: &str, duration: f64, bars: usize) -> Vec<f32> {
+    let mut seed = 0xcbf2_9ce4_8422_2325_u64;
+    for byte in name.bytes().chain((duration as u64).to_le_bytes()) {
+        seed ^= byte as u64;
+        seed = seed.wrapping_mul(0x0100_0000_01b3);
+    }
+
+    let mut prev = 0.5_f32;
+    (0..bars)
+        .map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            // 이웃 막대와 섞어서 파형처럼 부드럽게
+            let random = (seed % 1000) as f32 / 1000.0;
+            prev = (prev + random) / 2.0;
+            0.15 + prev * 0.85
+        })
+        .collect()
 }
 
 /// 애플리케이션 상태
 pub struct App {
     /// 현재 재생 중인 트랙 정보
     pub track: TrackInfo,
+    /// `track.player_position`을 마지막으로 맞춘 시각 (폴링 사이 재생 위치 보간용)
+    last_position_instant: Instant,
     /// 현재 볼륨 (0-100)
     pub volume: u8,
+    /// 볼륨 조절 대상 (Music.app / 시스템)
+    pub volume_target: VolumeTarget,
+    /// 볼륨을 잠시 낮춘(덕킹) 상태면 복원할 원래 볼륨
+    pub duck_restore: Option<u8>,
+    /// 음소거 상태 (`m` 키)
+    pub muted: bool,
+    /// 음소거 전 볼륨 (음소거 해제 시 복원)
+    pub volume_before_mute: u8,
+    /// 시스템 출력이 음소거되어 있는지 여부
+    pub system_muted: bool,
     /// 앱 실행 상태
     pub running: bool,
     /// 현재 앱 모드
     pub mode: AppMode,
+    /// 화면 레이아웃
+    pub layout: LayoutMode,
+    /// 확인 팝업을 닫은 뒤 돌아갈 모드
+    confirm_return_mode: AppMode,
     
     /// 이미지 프로토콜 Picker (터미널 그래픽스 프로토콜 감지용)
     pub picker: Picker,
     /// 현재 아트워크 이미지 프로토콜 (렌더링용)
     pub artwork: Option<StatefulProtocol>,
+    /// 아트워크 후보 URL 목록 (내장 아트워크가 있으면 그 파일 하나, 없으면 iTunes 검색 결과 순서)
+    artwork_candidates: Vec<String>,
+    /// 현재 표시 중인 아트워크 후보 인덱스
+    artwork_index: usize,
+    /// 현재 트랙 아트워크로 저장한 임시 파일 (트랙이 바뀌거나 종료할 때 삭제)
+    artwork_files: Vec<PathBuf>,
+    /// 앨범별로 이미 받아 둔 아트워크 파일 (키: 아티스트 + 앨범, 같은 앨범 곡으로 바뀌면 다시 받지 않음)
+    artwork_cache: HashMap<String, PathBuf>,
+    /// 첫 화면을 그린 뒤(터미널 그래픽스 질의가 끝난 뒤)에만 아트워크 반영
+    picker_ready: bool,
+    /// 아트워크 검색/다운로드/디코딩 작업
+    artwork_loader: ArtworkLoader,
+    /// 현재 트랙 아트워크를 찾는 중 (없음 대신 로딩 표시)
+    pub artwork_loading: bool,
+    /// 포커스 모드 아트워크 슬라이드쇼 활성화 여부
+    pub slideshow: bool,
+    /// 마지막으로 아트워크를 바꾼 시각 (슬라이드쇼 타이머)
+    slideshow_changed: Instant,
+    /// 화면보다 긴 제목/앨범의 마퀴 스크롤 위치 (트랙이 바뀌면 0)
+    pub marquee_offset: usize,
+    /// 마퀴를 마지막으로 이동한 시각
+    marquee_stepped: Instant,
+    /// 아트워크가 없을 때 그리는 막대 애니메이션 프레임 (재생 중에만 증가)
+    pub spectrum_tick: u64,
+    /// 마지막으로 애니메이션 프레임을 진행한 시각
+    spectrum_stepped: Instant,
+    /// 아트워크가 없을 때 표시할 파형 (트랙 변경 시 계산)
+    pub waveform: Vec<f32>,
+    /// 트랙 ID별 음량 파형 캐시 (계산 실패한 트랙은 빈 목록)
+    loudness: HashMap<String, Vec<f32>>,
+    /// 곡 변경 시 음량 파형, 챕터, 가사 등 부가 정보 로드 작업
+    track_loader: TrackLoader,
+    /// 현재 트랙의 챕터 목록
+    pub chapters: Vec<Chapter>,
     /// 마지막으로 로드한 트랙 이름 (변경 감지용)
     last_track_name: String,
+    /// 이 트랙(persistentID)이 끝나면 일시정지
+    pub pause_after_id: Option<String>,
+    /// 재생 대기열이 끝나면 비슷한 곡 이어서 재생
+    pub autoplay: bool,
+    /// Music.app 셔플 상태 (폴링으로 동기화)
+    pub shuffle: bool,
+    /// Music.app 반복 모드 (폴링으로 동기화)
+    pub repeat: RepeatMode,
 
     /// 검색 쿼리
     pub search_query: String,
-    /// 검색 결과
+    /// 검색 결과 (필터 적용 후)
     pub search_results: Vec<SearchResult>,
+    /// 필터 적용 전 전체 검색 결과
+    search_results_all: Vec<SearchResult>,
+    /// 검색 결과 필터 입력
+    pub search_filter_input: String,
+    /// 적용 중인 검색 결과 필터
+    pub search_filter: Option<ResultFilter>,
     /// 검색 결과 선택 인덱스
     pub search_result_index: usize,
     /// 검색 소스 모드
     pub search_mode: SearchMode,
+    /// 보관함 검색 범위로 지정한 플레이리스트 (None이면 보관함 전체)
+    pub search_playlist: Option<Playlist>,
+    /// 검색어 수정 전에 선택했던 결과 ID (다시 검색한 뒤 같은 곡을 선택하기 위함)
+    search_refine_id: Option<String>,
+    /// 마지막 검색어 입력 시각 (입력을 멈추면 자동 검색, 검색 후 None)
+    search_edited: Option<Instant>,
+    /// 명령 팔레트 입력
+    pub command_input: String,
+    /// vim식 숫자 접두사 (예: `5j`의 5, 입력 중이 아니면 None)
+    pending_count: Option<usize>,
+    /// `gg`의 첫 번째 `g`를 누른 상태
+    pending_g: bool,
+    /// 백그라운드 검색 실행기
+    searcher: Searcher,
+    /// 결과를 기다리는 검색 (None이면 검색 중이 아님)
+    pending_search: Option<PendingSearch>,
+    /// 현재 검색어로 검색을 마쳤는지 (결과가 없을 때 첫 검색 전과 구분해 안내)
+    pub search_performed: bool,
+
+    /// 스킵 기록
+    pub skip_log: SkipLog,
+    /// 스킵 기록 목록 선택 인덱스
+    pub skip_log_index: usize,
+
+    /// 현재 세션 재생 통계
+    pub session_stats: SessionStats,
+    /// 마지막으로 센 보관함 통계 (처음 열 때 한 번 세고 `r`로 다시 셈)
+    pub library_stats: Option<LibraryStats>,
+    /// 백그라운드에서 세는 중인 보관함 통계
+    library_stats_rx: Option<Receiver<anyhow::Result<LibraryStats>>>,
+
+    /// 라이브러리 브라우저 상태
+    pub browser: Browser,
+
+    /// 현재 곡 가사 (가사 보기를 열었을 때 로드)
+    pub lyrics: Lyrics,
+    /// 가사를 로드한 트랙 ID (트랙이 바뀌면 다시 로드)
+    lyrics_track_id: String,
+    /// 가사를 백그라운드에서 가져오는 중인지
+    pub lyrics_loading: bool,
+    /// 타임스탬프 없는 가사의 스크롤 위치 (줄)
+    pub lyrics_scroll: u16,
+
+    /// 다음에 재생될 곡 (읽을 수 없는 재생 소스면 None)
+    pub up_next: Option<UpNext>,
+    /// Up Next를 백그라운드에서 가져오는 중인지
+    pub up_next_loading: bool,
+    /// Up Next 목록 선택 인덱스
+    pub up_next_index: usize,
+
+    /// 보관함 플레이리스트 목록 (`p`로 열 때 로드)
+    pub playlists: Vec<Playlist>,
+    /// 플레이리스트 목록 선택 인덱스
+    pub playlist_index: usize,
+    /// 플레이리스트 목록을 검색 범위 선택용으로 열었는지 여부 (고르면 재생 대신 검색으로 복귀)
+    pub playlists_pick_scope: bool,
+    /// 이퀄라이저 상태 (폴링으로 동기화)
+    pub eq: EqState,
+    /// 이퀄라이저 프리셋 이름 목록 (`e`로 열 때 로드)
+    pub eq_presets: Vec<String>,
+    /// 이퀄라이저 프리셋 선택 인덱스
+    pub eq_index: usize,
+    /// AirPlay 출력 장치 목록
+    pub airplay_devices: Vec<AirPlayDevice>,
+    /// AirPlay 장치 목록 선택 인덱스
+    pub airplay_index: usize,
+
+    /// 현재 팝업 목록의 화면 위치 (렌더링 시 갱신)
+    pub list_hit: Option<ListHitArea>,
+    /// 화면에 그려진 재생 버튼 위치와 동작 (렌더링 시 갱신)
+    pub button_hits: Vec<(Rect, Action)>,
+    /// 진행 바 안쪽 영역 (렌더링 시 갱신, 클릭하면 해당 위치로 이동)
+    pub progress_hit: Option<Rect>,
+    /// 볼륨 바 안쪽 영역 (렌더링 시 갱신, 클릭하면 해당 볼륨으로 설정)
+    pub volume_hit: Option<Rect>,
+    /// 마지막 목록 클릭 (인덱스, 시각) - 더블 클릭 판정용
+    last_click: Option<(usize, Instant)>,
+
+    /// 기본 모드 키맵
+    pub keymap: KeyMap,
+    /// 화면 강조 색상
+    pub theme: Theme,
+    /// 현재 내장 테마 이름
+    pub theme_name: String,
+    /// 사용자 설정 (프로필 적용 후)
+    pub config: Config,
+    /// 프로필 적용 전 원래 설정
+    base_config: Config,
+    /// 적용 중인 프로필 이름
+    pub profile: Option<String>,
+    /// 타이틀 줄에 현재 곡과 진행 시간 표시
+    pub title_now_playing: bool,
+    /// 재생 위치를 남은 시간으로 표시
+    pub show_remaining: bool,
+    /// Music.app 실행 여부 (트랙 정보를 연속으로 읽지 못하면 false)
+    pub music_running: bool,
+    /// 트랙 정보를 연속으로 읽지 못한 횟수
+    track_failures: u32,
+    /// `g`를 눌러 퍼센트 이동 숫자를 기다리는 중
+    pub seek_prefix: bool,
+    /// Discord 활동 갱신기 (`discord_presence` 설정 시)
+    discord: Option<Presence>,
+    /// 곡이 바뀔 때마다 현재 곡을 쓸 파일 (`--nowplaying-file`)
+    nowplaying_file: Option<PathBuf>,
+    /// 전체 키 도움말 오버레이 표시 (모드와 관계없이 모든 화면 위에 표시)
+    pub show_help: bool,
+    /// 도움말 오버레이 스크롤 위치 (줄, 렌더링 시 끝을 넘지 않도록 보정)
+    pub help_scroll: u16,
+    /// 상태 메시지 (도움말 영역에 잠시 표시)
+    status: Option<(String, Instant)>,
 }
 
 impl App {
     /// 새로운 App 인스턴스 생성
-    pub fn new() -> Self {
+    pub fn new(config: Config) -> Self {
         // 터미널 그래픽스 프로토콜 감지 (실패 시 halfblocks 폴백)
         let picker = Picker::from_query_stdio().unwrap_or_else(|_| Picker::from_fontsize((8, 16)));
-        
-        Self {
+
+        // 키맵 프리셋 (알 수 없는 이름이면 기본 프리셋 + 경고)
+        let preset = KeyMapPreset::from_name(&config.keymap_preset);
+        let status = match preset {
+            Some(_) => None,
+            None => Some((format!("Unknown keymap preset '{}', using default", config.keymap_preset), Instant::now())),
+        };
+        let mut keymap = KeyMap::preset(preset.unwrap_or_default());
+        // 잘못된 사용자 키 바인딩은 건너뛰고 알림
+        let key_errors = keymap.apply_overrides(&config.keys);
+        let status = match key_errors.first() {
+            Some(first) if status.is_none() => {
+                let more = if key_errors.len() > 1 { format!(" (+{} more)", key_errors.len() - 1) } else { String::new() };
+                Some((format!("Key config: {}{}", first, more), Instant::now()))
+            }
+            _ => status,
+        };
+        // 테마 (알 수 없는 이름이면 기본 테마, 잘못된 색상은 건너뛰고 알림)
+        let (theme, color_errors) = match Theme::builtin(&config.theme) {
+            Some(theme) => theme.with_colors(&config.colors),
+            None => {
+                let (theme, _) = Theme::default().with_colors(&config.colors);
+                (theme, vec![format!("unknown theme '{}', using default", config.theme)])
+            }
+        };
+        let status = match color_errors.first() {
+            Some(first) if status.is_none() => Some((format!("Theme config: {}", first), Instant::now())),
+            _ => status,
+        };
+        // Discord 활동 (애플리케이션 ID가 없으면 켜지 않고 알림)
+        let discord = match (config.discord_presence, config.discord_client_id.as_deref()) {
+            (true, Some(id)) if !id.is_empty() => Some(Presence::spawn(id)),
+            _ => None,
+        };
+        let status = match status {
+            None if config.discord_presence && discord.is_none() => {
+                Some(("Discord presence needs discord_client_id".to_string(), Instant::now()))
+            }
+            _ => status,
+        };
+        jxa::set_http_timeout(config.http_timeout_secs);
+        jxa::set_persistent(config.persistent_osascript);
+
+        let mut app = Self {
             track: TrackInfo::default(),
+            last_position_instant: Instant::now(),
             volume: 50,
+            volume_target: config.volume_target,
+            duck_restore: None,
+            muted: false,
+            volume_before_mute: 0,
+            system_muted: false,
             running: true,
             mode: AppMode::Normal,
+            layout: LayoutMode::Full,
+            confirm_return_mode: AppMode::Normal,
             picker,
             artwork: None,
+            artwork_candidates: Vec::new(),
+            artwork_index: 0,
+            artwork_files: Vec::new(),
+            artwork_cache: HashMap::new(),
+            picker_ready: false,
+            artwork_loader: ArtworkLoader::default(),
+            artwork_loading: false,
+            slideshow: config.artwork_slideshow,
+            slideshow_changed: Instant::now(),
+            marquee_offset: 0,
+            marquee_stepped: Instant::now(),
+            spectrum_tick: 0,
+            spectrum_stepped: Instant::now(),
+            waveform: Vec::new(),
+            loudness: HashMap::new(),
+            track_loader: TrackLoader::default(),
+            chapters: Vec::new(),
             last_track_name: String::new(),
+            pause_after_id: None,
+            autoplay: config.autoplay_similar,
+            shuffle: false,
+            repeat: RepeatMode::Off,
             search_query: String::new(),
             search_results: Vec::new(),
+            search_results_all: Vec::new(),
+            search_filter_input: String::new(),
+            search_filter: None,
             search_result_index: 0,
             search_mode: SearchMode::Library,
+            search_playlist: None,
+            search_refine_id: None,
+            search_edited: None,
+            command_input: String::new(),
+            pending_count: None,
+            pending_g: false,
+            searcher: Searcher::default(),
+            pending_search: None,
+            search_performed: false,
+            skip_log: SkipLog::load(),
+            skip_log_index: 0,
+            session_stats: SessionStats::default(),
+            library_stats: None,
+            library_stats_rx: None,
+            browser: Browser::default(),
+            lyrics: Lyrics::default(),
+            lyrics_track_id: String::new(),
+            lyrics_loading: false,
+            lyrics_scroll: 0,
+            up_next: None,
+            up_next_loading: false,
+            up_next_index: 0,
+            playlists: Vec::new(),
+            playlist_index: 0,
+            playlists_pick_scope: false,
+            eq: EqState::default(),
+            eq_presets: Vec::new(),
+            eq_index: 0,
+            airplay_devices: Vec::new(),
+            airplay_index: 0,
+            list_hit: None,
+            button_hits: Vec::new(),
+            progress_hit: None,
+            volume_hit: None,
+            last_click: None,
+            title_now_playing: config.title_now_playing,
+            show_remaining: false,
+            music_running: true,
+            seek_prefix: false,
+            discord,
+            nowplaying_file: None,
+            track_failures: 0,
+            show_help: false,
+            help_scroll: 0,
+            keymap,
+            theme,
+            theme_name: config.theme.clone(),
+            base_config: config.clone(),
+            config,
+            profile: None,
+            status,
+        };
+
+        match app.base_config.profile.clone() {
+            Some(name) => app.apply_profile(&name),
+            None => app.select_output_device(),
+        }
+        app.restore_ui_state(UiState::load());
+        app
+    }
+
+    /// 지난 실행에서 저장한 화면 상태 복원 (저장된 값만 덮어씀)
+    fn restore_ui_state(&mut self, state: UiState) {
+        if let Some(layout) = state.layout {
+            self.layout = layout;
+        }
+        if let Some(slideshow) = state.slideshow {
+            self.slideshow = slideshow;
+        }
+        if let Some(search_mode) = state.search_mode {
+            self.search_mode = search_mode;
+        }
+        if let Some(title_now_playing) = state.title_now_playing {
+            self.title_now_playing = title_now_playing;
+        }
+        if let Some(show_remaining) = state.show_remaining {
+            self.show_remaining = show_remaining;
+        }
+        if let Some(name) = state.theme {
+            self.set_theme(&name);
+        }
+        if let Some(step) = state.volume_step.filter(|step| (1..=100).contains(step)) {
+            self.config.volume_step = step;
+        }
+    }
+
+    /// 다음 실행에 복원할 현재 화면 상태
+    pub fn ui_state(&self) -> UiState {
+        UiState {
+            layout: Some(self.layout),
+            slideshow: Some(self.slideshow),
+            search_mode: Some(self.search_mode),
+            title_now_playing: Some(self.title_now_playing),
+            show_remaining: Some(self.show_remaining),
+            theme: Some(self.theme_name.clone()).filter(|name| *name != self.base_config.theme),
+            volume_step: Some(self.config.volume_step).filter(|step| *step != self.base_config.volume_step),
+        }
+    }
+
+    /// 프로필 적용 (기본 설정 위에 프로필 값을 덮어씀)
+    pub fn apply_profile(&mut self, name: &str) {
+        let Some(config) = self.base_config.with_profile(name) else {
+            self.set_status(format!("Unknown profile '{}'", name));
+            return;
+        };
+
+        self.switch_config(config);
+        self.profile = Some(name.to_string());
+        self.set_status(format!("Profile: {}", name));
+    }
+
+    /// 프로필을 해제하고 설정 파일의 기본 설정으로 돌아감
+    pub fn reset_profile(&mut self) {
+        self.switch_config(self.base_config.clone());
+        self.profile = None;
+        self.set_status("Profile: default");
+    }
+
+    /// 프로필 전환 시 설정과 함께 볼륨 대상, 테마, 출력 장치 갱신
+    fn switch_config(&mut self, config: Config) {
+        self.volume_target = config.volume_target;
+        self.config = config;
+        let theme = self.config.theme.clone();
+        self.set_theme(&theme);
+        self.select_output_device();
+    }
+
+    /// 다음 프로필로 전환 (이름순으로 돌고 마지막 프로필 다음은 기본 설정)
+    pub fn cycle_profile(&mut self) {
+        let names = self.base_config.profile_names();
+        if names.is_empty() {
+            self.set_status("No profiles configured");
+            return;
+        }
+
+        let next = match self.profile.as_deref().and_then(|current| names.iter().position(|n| *n == current)) {
+            Some(index) => names.get(index + 1).map(|name| name.to_string()),
+            None => Some(names[0].to_string()),
+        };
+        match next {
+            Some(name) => self.apply_profile(&name),
+            None => self.reset_profile(),
+        }
+    }
+
+    /// 내장 테마로 전환 (`[colors]` 값은 그대로 적용, 알 수 없는 이름이면 무시)
+    fn set_theme(&mut self, name: &str) {
+        if let Some(theme) = Theme::builtin(name) {
+            self.theme = theme.with_colors(&self.config.colors).0;
+            self.theme_name = name.to_string();
+        }
+    }
+
+    /// 다음 내장 테마로 전환
+    pub fn cycle_theme(&mut self) {
+        let next = match BUILTIN_THEMES.iter().position(|name| *name == self.theme_name) {
+            Some(index) => BUILTIN_THEMES[(index + 1) % BUILTIN_THEMES.len()],
+            None => BUILTIN_THEMES[0],
+        };
+        self.set_theme(next);
+        self.set_status(format!("Theme: {}", next));
+    }
+
+    /// 볼륨 조절 단위 순환 (1 → 2 → 5 → 10, 프로필을 바꾸면 프로필 값으로 돌아감)
+    pub fn cycle_volume_step(&mut self) {
+        let next = VOLUME_STEPS
+            .iter()
+            .copied()
+            .find(|step| *step > self.config.volume_step)
+            .unwrap_or(VOLUME_STEPS[0]);
+        self.config.volume_step = next;
+        self.set_status(format!("Volume step: {}", next));
+    }
+
+    /// 설정된 AirPlay 출력 장치 선택
+    fn select_output_device(&mut self) {
+        if let Some(device) = self.config.output_device.clone()
+            && jxa::select_only_airplay_device(&device).is_err()
+        {
+            self.set_status(format!("Failed to select output '{}'", device));
         }
     }
 
-    /// 재생/일시정지 토글
+    /// 상태 메시지 설정
+    pub fn set_status(&mut self, message: impl Into<String>) {
+        self.status = Some((message.into(), Instant::now()));
+    }
+
+    /// 표시 중인 상태 메시지 (만료되면 None)
+    pub fn status(&self) -> Option<&str> {
+        self.status
+            .as_ref()
+            .filter(|(_, at)| at.elapsed() < STATUS_DURATION)
+            .map(|(message, _)| message.as_str())
+    }
+
+    /// 재생/일시정지 토글 (stopped면 설정된 동작으로 재생 시작)
     pub fn toggle_play_pause(&mut self) {
-        let _ = jxa::play_pause();
+        if let Ok(false) = jxa::play_pause() {
+            self.start_playback();
+        }
+    }
+
+    /// 설정된 시작 동작으로 재생 시작 (`pick`이면 플레이리스트 목록을 열어 고르게 함)
+    pub fn start_playback(&mut self) {
+        if self.config.start_action == StartAction::Pick {
+            self.open_playlists();
+            return;
+        }
+        let _ = jxa::start_playback(self.config.start_action, self.config.start_playlist.as_deref());
     }
 
     /// 다음 곡
     pub fn next_track(&mut self) {
+        if self.handle_skip_while_stopped() {
+            return;
+        }
+        // 직접 곡을 넘기면 "현재 곡 후 일시정지" 해제
+        self.pause_after_id = None;
         let _ = jxa::next_track();
     }
 
     /// 이전 곡
     pub fn previous_track(&mut self) {
+        if self.handle_skip_while_stopped() {
+            return;
+        }
+        self.pause_after_id = None;
         let _ = jxa::previous_track();
     }
 
-    /// 볼륨 증가
-    pub fn volume_up(&mut self) {
-        self.volume = (self.volume + 5).min(100);
-        let _ = jxa::set_volume(self.volume);
+    /// 정지 상태에서 이전/다음 곡을 누른 경우 처리 (처리했으면 true)
+    /// `skip_starts_playback` 설정 시 재생을 시작하고, 아니면 안내 메시지만 표시
+    fn handle_skip_while_stopped(&mut self) -> bool {
+        if self.track.state != PlayerState::Stopped {
+            return false;
+        }
+        if self.config.skip_starts_playback {
+            self.start_playback();
+        } else {
+            self.set_status("Nothing is playing");
+        }
+        true
     }
 
-    /// 볼륨 감소
-    pub fn volume_down(&mut self) {
-        self.volume = self.volume.saturating_sub(5);
-        let _ = jxa::set_volume(self.volume);
+    /// 현재 곡이 끝나면 일시정지 토글
+    pub fn toggle_pause_after_current(&mut self) {
+        if self.pause_after_id.take().is_some() {
+            self.set_status("Pause after current: off");
+        } else if self.track.id.is_empty() {
+            self.set_status("Nothing is playing");
+        } else {
+            self.pause_after_id = Some(self.track.id.clone());
+            self.set_status("Pause after current: on");
+        }
     }
 
-    /// 트랙 정보 업데이트 (폴링)
-    pub fn update(&mut self) {
-        if let Ok(track) = jxa::get_current_track() {
-            // 트랙이 변경되었는지 확인
-            let track_changed = track.name != self.last_track_name;
-            self.track = track;
-            
-            // 트랙이 변경되었으면 아트워크 업데이트
-            if track_changed {
-                self.last_track_name = self.track.name.clone();
-                self.update_artwork();
-            }
+    /// 대상 곡이 끝나 다음 곡으로 넘어가면 일시정지 (폴링마다 호출)
+    /// 끝나기 전에 멈추면 곡 끝부분이 잘리므로 곡이 바뀐 뒤 멈추고 다음 곡을 처음으로 되돌립니다.
+    fn check_pause_after_current(&mut self) {
+        let Some(target) = &self.pause_after_id else {
+            return;
+        };
+        if self.track.id == *target {
+            return;
         }
-        if let Ok(vol) = jxa::get_volume() {
-            self.volume = vol;
+
+        self.pause_after_id = None;
+        let _ = jxa::pause();
+        if jxa::set_player_position(0.0).is_ok() {
+            self.track.player_position = 0.0;
         }
+        self.set_status("Paused after track");
     }
 
-    /// 아트워크 업데이트
-    fn update_artwork(&mut self) {
-        self.artwork = None;
-        
-        if let Ok(Some(path)) = jxa::get_artwork_path() {
-            if let Ok(reader) = ImageReader::open(&path) {
-                if let Ok(dyn_img) = reader.decode() {
-                    self.artwork = Some(self.picker.new_resize_protocol(dyn_img));
-                }
+    /// 비슷한 곡 자동 재생 전환
+    pub fn toggle_autoplay(&mut self) {
+        self.autoplay = !self.autoplay;
+        self.set_status(if self.autoplay { "Autoplay similar: on" } else { "Autoplay similar: off" });
+    }
+
+    /// 셔플 전환
+    pub fn toggle_shuffle(&mut self) {
+        self.set_shuffle(!self.shuffle);
+    }
+
+    /// 셔플 켜기/끄기 (Music.app에서 다시 읽어 실제 상태 반영)
+    pub fn set_shuffle(&mut self, enabled: bool) {
+        if jxa::set_shuffle(enabled).is_err() {
+            self.set_status("Failed to toggle shuffle");
+            return;
+        }
+        self.shuffle = jxa::get_shuffle().unwrap_or(enabled);
+        self.set_status(if self.shuffle { "Shuffle: on" } else { "Shuffle: off" });
+    }
+
+    /// 현재 트랙 별점 설정 (재생/일시정지 중인 트랙에만 적용)
+    pub fn rate_current_track(&mut self, stars: u8) {
+        if self.track.state == PlayerState::Stopped || self.track.id.is_empty() {
+            self.set_status("Nothing is playing");
+            return;
+        }
+        if jxa::set_rating(stars).is_err() {
+            self.set_status("Failed to set rating");
+            return;
+        }
+        self.track.rating = jxa::get_rating().unwrap_or(stars);
+        self.set_status(match self.track.rating {
+            0 => "Rating cleared".to_string(),
+            rating => format!("Rated {}", stars_text(rating)),
+        });
+    }
+
+    /// 현재 트랙 좋아요 켜기/끄기 (켜면 싫어요는 해제)
+    pub fn toggle_loved(&mut self) {
+        if self.track.state == PlayerState::Stopped || self.track.id.is_empty() {
+            self.set_status("Nothing is playing");
+            return;
+        }
+        let loved = !self.track.loved;
+        if jxa::set_loved(loved).is_err() {
+            self.set_status("Failed to update loved");
+            return;
+        }
+        self.track.loved = loved;
+        if loved {
+            self.track.disliked = false;
+        }
+        self.set_status(if loved { "Loved ♥" } else { "Removed from loved" });
+    }
+
+    /// 반복 모드 순환 (off → all → one)
+    pub fn cycle_repeat(&mut self) {
+        self.set_repeat(self.repeat.next());
+    }
+
+    /// 반복 모드 설정 (Music.app에서 다시 읽어 실제 상태 반영)
+    pub fn set_repeat(&mut self, mode: RepeatMode) {
+        if jxa::set_repeat(mode).is_err() {
+            self.set_status("Failed to change repeat mode");
+            return;
+        }
+        self.repeat = jxa::get_repeat().unwrap_or(mode);
+        self.set_status(format!("Repeat: {}", self.repeat.as_str()));
+    }
+
+    /// 재생 대기열이 끝나 정지했으면 끝난 곡과 비슷한 곡들을 이어서 재생
+    fn check_autoplay(&mut self, previous: &TrackInfo) {
+        let queue_ended = self.autoplay
+            && previous.state == PlayerState::Playing
+            && self.track.state == PlayerState::Stopped
+            && previous.duration > 0.0
+            && previous.duration - previous.player_position <= AUTOPLAY_END_THRESHOLD;
+        if !queue_ended {
+            return;
+        }
+
+        // 보관함 검색이 느릴 수 있으므로 백그라운드에서 찾고 결과는 `receive_track_data`에서 안내
+        self.track_loader.start_autoplay(previous, AUTOPLAY_TRACKS);
+    }
+
+    /// 화면에 표시할 재생 위치 (재생 중이면 마지막 폴링 이후 흐른 시간을 더해 진행 바가 매끄럽게 움직임)
+    pub fn display_position(&self) -> f64 {
+        if self.track.state != PlayerState::Playing {
+            return self.track.player_position;
+        }
+        let position = self.track.player_position + self.last_position_instant.elapsed().as_secs_f64();
+        if self.track.duration > 0.0 { position.min(self.track.duration) } else { position }
+    }
+
+    /// 지정한 위치로 이동 (초, `[0, duration]`으로 제한)
+    pub fn seek_to(&mut self, seconds: f64) {
+        let pos = seconds.clamp(0.0, self.track.duration.max(0.0));
+        if jxa::set_player_position(pos).is_ok() {
+            self.track.player_position = pos;
+            self.last_position_instant = Instant::now();
+            // Discord 경과 시간은 곡 시작 시각으로 계산되므로 이동한 위치로 다시 보냄
+            if let Some(presence) = &mut self.discord {
+                presence.resync(&self.track);
             }
         }
     }
 
-    /// 앱 종료
-    pub fn quit(&mut self) {
-        self.running = false;
+    /// 트랙 길이의 `percent`% 위치로 이동
+    pub fn seek_percent(&mut self, percent: u8) {
+        if self.track.id.is_empty() {
+            return;
+        }
+        self.seek_to(self.track.duration * percent.min(100) as f64 / 100.0);
     }
 
-    /// 재생 중인지 확인
-    #[allow(dead_code)]
-    pub fn is_playing(&self) -> bool {
-        self.track.state == PlayerState::Playing
+    /// 현재 위치에서 상대 이동 (초, 음수면 뒤로)
+    pub fn seek_by(&mut self, delta: f64) {
+        if self.track.id.is_empty() {
+            return;
+        }
+        self.seek_to(self.display_position() + delta);
     }
 
-    /// 검색 수행
-    pub fn perform_search(&mut self) {
-        let results = match self.search_mode {
-            SearchMode::Library => jxa::search_library(&self.search_query),
-            SearchMode::AppleMusic => jxa::search_apple_music(&self.search_query),
+    /// 현재 재생 위치의 챕터 인덱스
+    pub fn current_chapter_index(&self) -> Option<usize> {
+        let position = self.display_position();
+        self.chapters
+            .iter()
+            .rposition(|chapter| chapter.start <= position)
+    }
+
+    /// 다음 챕터로 이동 (마지막 챕터면 다음 곡, 챕터가 없으면 30초 앞으로)
+    pub fn next_chapter(&mut self) {
+        if self.chapters.is_empty() {
+            self.seek_by(SEEK_STEP_LONG);
+            return;
+        }
+        let position = self.display_position();
+        let next = self
+            .chapters
+            .iter()
+            .find(|chapter| chapter.start > position)
+            .map(|chapter| chapter.start);
+        match next {
+            Some(start) => self.seek_to(start),
+            None => self.next_track(),
+        }
+    }
+
+    /// 이전 챕터로 이동 (챕터 시작 직후가 아니면 현재 챕터 처음으로, 챕터가 없으면 30초 뒤로)
+    pub fn previous_chapter(&mut self) {
+        if self.chapters.is_empty() {
+            self.seek_by(-SEEK_STEP_LONG);
+            return;
+        }
+        let Some(index) = self.current_chapter_index() else {
+            self.previous_track();
+            return;
         };
 
-        if let Ok(results) = results {
-            self.search_results = results;
-            self.search_result_index = 0;
-            if !self.search_results.is_empty() {
-                self.mode = AppMode::SearchResults;
-            }
+        let current_start = self.chapters[index].start;
+        if self.display_position() - current_start > CHAPTER_RESTART_THRESHOLD || index == 0 {
+            self.seek_to(current_start);
+        } else {
+            self.seek_to(self.chapters[index - 1].start);
         }
     }
 
-    /// 검색 소스 토글
-    pub fn toggle_search_mode(&mut self) {
-        self.search_mode = match self.search_mode {
-            SearchMode::Library => SearchMode::AppleMusic,
-            SearchMode::AppleMusic => SearchMode::Library,
+    /// 현재 곡 이후의 앨범 나머지 트랙을 이어서 재생
+    /// 현재 곡과 나머지 트랙으로 임시 큐를 만든 뒤 현재 위치부터 계속 재생합니다.
+    pub fn queue_rest_of_album(&mut self) {
+        if self.track.id.is_empty() {
+            self.set_status("Nothing is playing");
+            return;
+        }
+
+        // 보관함 전체를 훑으므로 백그라운드에서 처리하고 `receive_track_data`에서 원래 위치로 이동
+        self.track_loader.queue_rest_of_album(&self.track, self.display_position());
+        self.set_status("Queueing rest of album…");
+    }
+
+    /// 현재 곡을 스킵 기록에 남기고 다음 곡으로 이동
+    pub fn skip_and_remember(&mut self) {
+        if self.track.id.is_empty() {
+            self.set_status("Nothing is playing");
+            return;
+        }
+
+        let count = self.skip_log.record(&self.track);
+        if self.config.skip_marks_disliked {
+            let _ = jxa::set_disliked(true);
+        }
+        let _ = jxa::next_track();
+
+        match self.skip_log.save() {
+            Ok(()) => self.set_status(format!("Skipped \"{}\" ({}x)", self.track.name, count)),
+            Err(_) => self.set_status("Failed to save skip log"),
+        }
+    }
+
+    /// 스킵 기록 보기 열기
+    pub fn open_skip_log(&mut self) {
+        self.skip_log_index = 0;
+        self.mode = AppMode::SkipLog;
+    }
+
+    /// 스킵 기록 선택 위로 이동
+    pub fn skip_log_select_prev(&mut self) {
+        self.skip_log_index = self.skip_log_index.saturating_sub(1);
+    }
+
+    /// 스킵 기록 선택 아래로 이동
+    pub fn skip_log_select_next(&mut self) {
+        if self.skip_log_index < self.skip_log.entries.len().saturating_sub(1) {
+            self.skip_log_index += 1;
+        }
+    }
+
+    /// 재생 기록을 파일로 내보내기 (내보낸 곡 수 반환)
+    /// `persistent`가 true면 누적 기록(`history.jsonl`), 아니면 이번 세션 기록을 사용
+    pub fn export_history(&self, path: &std::path::Path, format: ExportFormat, persistent: bool) -> anyhow::Result<usize> {
+        let loaded;
+        let entries = if persistent {
+            loaded = history::load();
+            &loaded
+        } else {
+            &self.session_stats.history
         };
+        history::export(entries, path, format)?;
+        Ok(entries.len())
     }
 
-    /// 검색 결과 선택 및 재생
-    pub fn search_play_selection(&mut self) {
-        if let Some(result) = self.search_results.get(self.search_result_index) {
-            let _ = jxa::play_track_by_id(&result.id);
-            // 재생 후 검색 모드 종료
+    /// 이번 세션 기록을 설정 디렉토리의 exports/에 Markdown으로 내보내기
+    fn export_session_history(&mut self) {
+        let Some(dir) = Config::dir() else {
+            self.set_status("Cannot find HOME directory");
+            return;
+        };
+        let format = ExportFormat::Markdown;
+        let stamp = history::format_timestamp(
+            std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0),
+        )
+        .replace([' ', ':'], "-");
+        let path = dir.join("exports").join(format!("session-{}.{}", stamp, format.extension()));
+
+        match self.export_history(&path, format, false) {
+            Ok(count) => self.set_status(format!("Exported {} tracks to {}", count, path.display())),
+            Err(e) => self.set_status(format!("Export failed: {:#}", e)),
+        }
+    }
+
+    /// 라이브러리 브라우저 열기 (이전 탐색 위치 유지)
+    pub fn open_browser(&mut self) {
+        self.mode = AppMode::Browser;
+    }
+
+    /// 브라우저에서 선택한 항목으로 들어가거나 트랙 재생
+    pub fn browser_enter(&mut self) {
+        match self.browser.enter() {
+            Ok(Some(PlayRequest::Playlist { id, name, index })) => match jxa::play_playlist_from(&id, index) {
+                Ok(()) => self.set_status(format!("Playing {}", name)),
+                Err(_) => self.set_status("Failed to play playlist"),
+            },
+            Ok(Some(PlayRequest::Queue(ids))) => match jxa::play_tracks_as_queue(&ids) {
+                Ok(()) => self.set_status(format!("Playing {} tracks", ids.len())),
+                Err(_) => self.set_status("Failed to queue tracks"),
+            },
+            Ok(None) => {}
+            Err(_) => self.set_status("Failed to load library"),
+        }
+    }
+
+    /// 브라우저 상위 단계로 (최상위면 닫기)
+    pub fn browser_back(&mut self) {
+        if !self.browser.back() {
             self.mode = AppMode::Normal;
-            self.search_query.clear();
-            self.search_results.clear();
         }
     }
 
-    /// 검색 결과 선택 위로 이동
-    pub fn search_select_prev(&mut self) {
-        if self.search_result_index > 0 {
-            self.search_result_index -= 1;
+    /// 가사 보기 열기
+    pub fn open_lyrics(&mut self) {
+        if self.track.id.is_empty() {
+            self.set_status("Nothing is playing");
+            return;
         }
+        self.load_lyrics();
+        self.mode = AppMode::Lyrics;
     }
 
-    /// 검색 결과 선택 아래로 이동
-    pub fn search_select_next(&mut self) {
-        if self.search_result_index < self.search_results.len().saturating_sub(1) {
-            self.search_result_index += 1;
+    /// 현재 트랙 가사 로드 시작 (이미 로드했거나 로드 중인 트랙이면 생략)
+    /// 가사는 백그라운드에서 가져오고 도착하면 `receive_track_data`에서 반영합니다.
+    fn load_lyrics(&mut self) {
+        if self.lyrics_track_id == self.track.id {
+            return;
         }
+        self.lyrics = Lyrics::default();
+        self.lyrics_track_id = self.track.id.clone();
+        self.lyrics_loading = true;
+        self.lyrics_scroll = 0;
+        self.track_loader.fetch_lyrics(&self.track.id);
+    }
+
+    /// 현재 곡 내보내기 파일 지정 후 바로 한 번 쓰기
+    pub fn set_nowplaying_file(&mut self, path: PathBuf) {
+        self.nowplaying_file = Some(path);
+        self.write_nowplaying_file();
+    }
+
+    /// 현재 곡을 "아티스트 - 제목"으로 내보내기 (정지 상태면 빈 파일)
+    /// 읽는 쪽이 반쯤 쓴 내용을 보지 않도록 임시 파일에 쓴 뒤 이름을 바꿉니다.
+    fn write_nowplaying_file(&mut self) {
+        let Some(path) = &self.nowplaying_file else {
+            return;
+        };
+        let text = if self.track.state == PlayerState::Stopped || self.track.name.is_empty() {
+            String::new()
+        } else {
+            format!("{} - {}", self.track.artist, self.track.name)
+        };
+
+        let mut temp = path.clone().into_os_string();
+        temp.push(".tmp");
+        let written = std::fs::write(&temp, text).and_then(|()| std::fs::rename(&temp, path));
+        if written.is_err() {
+            self.set_status(format!("Failed to write {}", path.display()));
+        }
+    }
+
+    /// 종료된 Music.app 다시 실행 후 즉시 상태 갱신
+    pub fn relaunch_music(&mut self) {
+        if jxa::ensure_music_ready().is_err() {
+            self.set_status("Failed to launch Music.app");
+            return;
+        }
+        self.update();
+        if self.music_running {
+            self.set_status("Music.app launched");
+        }
+    }
+
+    /// 키 도움말 오버레이 열기/닫기
+    pub fn toggle_help(&mut self) {
+        self.show_help = !self.show_help;
+        self.help_scroll = 0;
+    }
+
+    /// 도움말 위로 스크롤
+    pub fn help_scroll_up(&mut self, lines: u16) {
+        self.help_scroll = self.help_scroll.saturating_sub(lines);
+    }
+
+    /// 도움말 아래로 스크롤
+    pub fn help_scroll_down(&mut self, lines: u16) {
+        self.help_scroll = self.help_scroll.saturating_add(lines);
+    }
+
+    /// 가사 위로 스크롤
+    pub fn lyrics_scroll_up(&mut self, lines: u16) {
+        self.lyrics_scroll = self.lyrics_scroll.saturating_sub(lines);
+    }
+
+    /// 가사 아래로 스크롤 (마지막 줄까지)
+    pub fn lyrics_scroll_down(&mut self, lines: u16) {
+        let max = self.lyrics.lines.len().saturating_sub(1) as u16;
+        self.lyrics_scroll = (self.lyrics_scroll + lines).min(max);
     }
-}
 
+    /// Up Next 목록 열기 (읽을 수 없으면 팝업에 안내 문구 표시)
+    pub fn open_up_next(&mut self) {
+        self.up_next = None;
+        self.up_next_loading = true;
+        self.up_next_index = 0;
+        self.track_loader.fetch_up_next(UP_NEXT_TRACKS);
+        self.mode = AppMode::UpNext;
+    }
+
+    /// Up Next 선택 위로 이동
+    pub fn up_next_select_prev(&mut self) {
+        self.up_next_index = self.up_next_index.saturating_sub(1);
+    }
+
+    /// Up Next 선택 아래로 이동
+    pub fn up_next_select_next(&mut self) {
+        let len = self.up_next.as_ref().map_or(0, |up_next| up_next.tracks.len());
+        if self.up_next_index + 1 < len {
+            self.up_next_index += 1;
+        }
+    }
+
+    /// 플레이리스트 목록 열기 (비어 있어도 열고 안내 문구 표시)
+    pub fn open_playlists(&mut self) {
+        match jxa::get_playlists() {
+            Ok(playlists) => {
+                self.playlists = playlists;
+                self.playlist_index = 0;
+                self.playlists_pick_scope = false;
+                self.mode = AppMode::Playlists;
+            }
+            Err(_) => self.set_status("Failed to read playlists"),
+        }
+    }
+
+    /// 플레이리스트 목록 닫기 (검색 범위를 고르던 중이면 검색 입력으로 복귀)
+    pub fn close_playlists(&mut self) {
+        self.mode = if std::mem::take(&mut self.playlists_pick_scope) {
+            AppMode::SearchInput
+        } else {
+            AppMode::Normal
+        };
+    }
+
+    /// 보관함 검색 범위 전환: 지정된 플레이리스트가 있으면 보관함 전체로, 없으면 플레이리스트 고르기
+    pub fn toggle_search_scope(&mut self) {
+        if self.search_playlist.take().is_some() {
+            self.search_input_changed();
+            return;
+        }
+        self.open_playlists();
+        self.playlists_pick_scope = self.mode == AppMode::Playlists;
+    }
 
+    /// 플레이리스트 선택 위로 이동
+    pub fn playlists_select_prev(&mut self) {
+        self.playlist_index = self.playlist_index.saturating_sub(1);
+    }
+
+    /// 플레이리스트 선택 아래로 이동
+    pub fn playlists_select_next(&mut self) {
+        if self.playlist_index + 1 < self.playlists.len() {
+            self.playlist_index += 1;
+        }
+    }
+
+    /// 선택한 플레이리스트 재생 후 목록 닫기 (검색 범위를 고르던 중이면 범위로 지정)
+    pub fn playlists_play_selection(&mut self) {
+        let Some(playlist) = self.playlists.get(self.playlist_index) else {
+            return;
+        };
+        if self.playlists_pick_scope {
+            self.search_playlist = Some(playlist.clone());
+            self.close_playlists();
+            self.search_input_changed();
+            return;
+        }
+        match jxa::play_playlist(&playlist.id) {
+            Ok(()) => {
+                let message = format!("Playing {}", playlist.name);
+                self.set_status(message);
+                self.mode = AppMode::Normal;
+            }
+            Err(_) => self.set_status("Failed to play playlist"),
+        }
+    }
+
+    /// 이퀄라이저 프리셋 목록 열기 (현재 프리셋 선택)
+    pub fn open_equalizer(&mut self) {
+        match jxa::get_eq_presets() {
+            Ok(presets) => {
+                self.eq_index = presets.iter().position(|name| *name == self.eq.preset).unwrap_or(0);
+                self.eq_presets = presets;
+                self.mode = AppMode::Equalizer;
+            }
+            Err(_) => self.set_status("Failed to read EQ presets"),
+        }
+    }
+
+    /// 이퀄라이저 프리셋 선택 위로 이동
+    pub fn eq_select_prev(&mut self) {
+        self.eq_index = self.eq_index.saturating_sub(1);
+    }
+
+    /// 이퀄라이저 프리셋 선택 아래로 이동
+    pub fn eq_select_next(&mut self) {
+        if self.eq_index + 1 < self.eq_presets.len() {
+            self.eq_index += 1;
+        }
+    }
+
+    /// 선택한 프리셋 적용 (목록은 열어 두어 다른 프리셋과 비교 가능)
+    pub fn eq_apply_selection(&mut self) {
+        let Some(preset) = self.eq_presets.get(self.eq_index).cloned() else {
+            return;
+        };
+        match jxa::set_eq_preset(&preset) {
+            Ok(()) => {
+                self.set_status(format!("EQ: {}", preset));
+                self.eq = EqState { enabled: true, preset };
+            }
+            Err(_) => self.set_status("Failed to set EQ preset"),
+        }
+    }
+
+    /// 이퀄라이저 켜기/끄기
+    pub fn toggle_eq_enabled(&mut self) {
+        let enabled = !self.eq.enabled;
+        match jxa::set_eq_enabled(enabled) {
+            Ok(()) => {
+                self.eq.enabled = enabled;
+                self.set_status(if enabled { "EQ on" } else { "EQ off" });
+            }
+            Err(_) => self.set_status("Failed to toggle EQ"),
+        }
+    }
+
+    /// AirPlay 장치 목록 열기
+    pub fn open_airplay(&mut self) {
+        match jxa::get_airplay_devices() {
+            Ok(devices) if !devices.is_empty() => {
+                self.airplay_devices = devices;
+                self.airplay_index = 0;
+                self.mode = AppMode::AirPlay;
+            }
+            Ok(_) => self.set_status("No AirPlay devices available"),
+            Err(_) => self.set_status("Failed to read AirPlay devices"),
+        }
+    }
+
+    /// AirPlay 장치 선택 위로 이동
+    pub fn airplay_select_prev(&mut self) {
+        self.airplay_index = self.airplay_index.saturating_sub(1);
+    }
+
+    /// AirPlay 장치 선택 아래로 이동
+    pub fn airplay_select_next(&mut self) {
+        if self.airplay_index < self.airplay_devices.len().saturating_sub(1) {
+            self.airplay_index += 1;
+        }
+    }
+
+    /// 선택한 AirPlay 장치를 출력 그룹에 추가/제거
+    pub fn airplay_toggle_selected(&mut self) {
+        let Some(device) = self.airplay_devices.get(self.airplay_index) else {
+            return;
+        };
+
+        // 출력 장치가 하나도 없을 수는 없으므로 마지막 장치는 빼지 않음
+        let selected_count = self.airplay_devices.iter().filter(|d| d.selected).count();
+        if device.selected && selected_count <= 1 {
+            self.set_status("At least one output device must stay selected");
+            return;
+        }
+
+        let selected = !device.selected;
+        if jxa::set_airplay_device_selected(&device.name, selected).is_err() {
+            self.set_status("Failed to change AirPlay output");
+            return;
+        }
+        self.airplay_devices[self.airplay_index].selected = selected;
+    }
+
+    /// 선택한 AirPlay 장치 볼륨 조절 (delta만큼)
+    pub fn airplay_adjust_volume(&mut self, delta: i16) {
+        let Some(device) = self.airplay_devices.get_mut(self.airplay_index) else {
+            return;
+        };
+
+        let volume = (device.volume as i16 + delta).clamp(0, 100) as u8;
+        device.volume = volume;
+        let name = device.name.clone();
+        if jxa::set_airplay_device_volume(&name, volume).is_err() {
+            self.set_status("Failed to set AirPlay device volume");
+        }
+    }
+
+    /// 볼륨 증가 (`volume_step`만큼)
+    pub fn volume_up(&mut self) {
+        self.adjust_volume(self.config.volume_step as i16);
+    }
+
+    /// 볼륨 감소 (`volume_step`만큼)
+    pub fn volume_down(&mut self) {
+        self.adjust_volume(-(self.config.volume_step as i16));
+    }
+
+    /// 덕킹 전환: 볼륨을 `duck_volume`까지 잠시 낮추고, 다시 누르면 원래 볼륨으로 복원
+    pub fn toggle_duck(&mut self) {
+        match self.duck_restore.take() {
+            Some(previous) => {
+                self.set_volume(previous);
+                self.set_status(format!("Volume restored to {}%", previous));
+            }
+            None => {
+                let previous = self.volume;
+                self.set_volume(self.config.duck_volume.min(previous));
+                self.duck_restore = Some(previous);
+                self.set_status(format!("Ducked to {}%", self.volume));
+            }
+        }
+    }
+
+    /// 음소거 전환 (해제 시 음소거 전 볼륨 복원)
+    pub fn toggle_mute(&mut self) {
+        if self.muted {
+            self.set_volume(self.volume_before_mute);
+            self.set_status(format!("Unmuted ({}%)", self.volume));
+        } else {
+            self.volume_before_mute = self.volume;
+            self.set_volume(0);
+            self.muted = true;
+            self.set_status("Muted");
+        }
+    }
+
+    /// 볼륨을 delta만큼 조절
+    /// `volume_wrap` 설정 시 100을 넘으면 0으로, 0 아래로 내려가면 100으로 순환 (기본은 0-100 제한)
+    pub fn adjust_volume(&mut self, delta: i16) {
+        // 직접 볼륨을 바꾸면 덕킹 복원/음소거 취소
+        self.duck_restore = None;
+        self.muted = false;
+        let target = self.volume as i16 + delta;
+        self.volume = match target {
+            _ if !self.config.volume_wrap => target.clamp(0, 100) as u8,
+            101.. => 0,
+            ..0 => 100,
+            _ => target as u8,
+        };
+        let _ = self.write_volume(self.volume);
+    }
+
+    /// 타이틀 줄 현재 곡 표시 전환
+    pub fn toggle_title_now_playing(&mut self) {
+        self.title_now_playing = !self.title_now_playing;
+    }
+
+    /// 재생 위치 표시 방식 전환 (경과 시간 ↔ 남은 시간)
+    pub fn toggle_show_remaining(&mut self) {
+        self.show_remaining = !self.show_remaining;
+    }
+
+    /// 볼륨 조절 대상 전환 (Music.app ↔ 시스템)
+    pub fn toggle_volume_target(&mut self) {
+        self.volume_target = match self.volume_target {
+            VolumeTarget::App => VolumeTarget::System,
+            VolumeTarget::System => VolumeTarget::App,
+        };
+        if let Ok(vol) = self.read_volume() {
+            self.volume = vol;
+        }
+        self.set_status(match self.volume_target {
+            VolumeTarget::App => "Volume keys control Music.app",
+            VolumeTarget::System => "Volume keys control system output",
+        });
+    }
+
+    /// 현재 대상의 볼륨 읽기
+    fn read_volume(&self) -> anyhow::Result<u8> {
+        match self.volume_target {
+            VolumeTarget::App => jxa::get_volume(),
+            VolumeTarget::System => jxa::get_system_volume(),
+        }
+    }
+
+    /// 현재 대상의 볼륨 설정
+    fn write_volume(&self, level: u8) -> anyhow::Result<()> {
+        match self.volume_target {
+            VolumeTarget::App => jxa::set_volume(level),
+            VolumeTarget::System => jxa::set_system_volume(level),
+        }
+    }
+
+    /// 볼륨을 지정한 값으로 설정 (덕킹 복원/음소거 취소)
+    pub fn set_volume(&mut self, level: u8) {
+        self.duck_restore = None;
+        self.muted = false;
+        self.volume = level.min(100);
+        let _ = self.write_volume(self.volume);
+    }
+
+    /// FIFO나 명령 팔레트로 받은 명령 실행 (키 입력과 같은 동작 사용)
+    pub fn run_command(&mut self, command: Command) {
+        match command {
+            Command::Play if !self.is_playing() => self.perform(Action::PlayPause),
+            Command::Pause if self.is_playing() => self.perform(Action::PlayPause),
+            Command::Play | Command::Pause => {}
+            Command::Toggle => self.perform(Action::PlayPause),
+            Command::Next => self.perform(Action::NextTrack),
+            Command::Prev => self.perform(Action::PreviousTrack),
+            Command::Volume(level) => self.set_volume(level),
+            Command::Seek(seconds) => self.seek_to(seconds),
+            Command::Shuffle(enabled) => self.set_shuffle(enabled),
+            Command::Repeat(mode) => self.set_repeat(mode),
+            Command::Action(action) => self.perform(action),
+        }
+    }
+
+    /// 명령 팔레트 열기
+    pub fn open_command(&mut self) {
+        self.command_input.clear();
+        self.mode = AppMode::Command;
+    }
+
+    /// 명령 팔레트에 입력한 명령 실행 (알 수 없는 명령이면 입력창을 유지)
+    pub fn execute_command_input(&mut self) {
+        let Some(command) = Command::parse_palette(&self.command_input) else {
+            self.set_status(format!("Unknown command: {}", self.command_input.trim()));
+            return;
+        };
+        // 팝업을 여는 동작도 있으므로 먼저 기본 모드로 돌아감
+        self.mode = AppMode::Normal;
+        self.run_command(command);
+    }
+
+    /// 보관함 통계 열기 (아직 세지 않았으면 백그라운드에서 세기 시작)
+    pub fn open_library_stats(&mut self) {
+        if self.library_stats.is_none() && self.library_stats_rx.is_none() {
+            self.refresh_library_stats();
+        }
+        self.mode = AppMode::LibraryStats;
+    }
+
+    /// 보관함 통계 다시 세기 (큰 보관함은 몇 초 걸리므로 별도 스레드에서)
+    pub fn refresh_library_stats(&mut self) {
+        if self.library_stats_rx.is_some() {
+            return;
+        }
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = tx.send(jxa::get_library_stats());
+        });
+        self.library_stats_rx = Some(rx);
+    }
+
+    /// 보관함 통계를 세는 중인지
+    pub fn library_stats_loading(&self) -> bool {
+        self.library_stats_rx.is_some()
+    }
+
+    /// 백그라운드에서 끝난 보관함 통계 반영 (메인 루프에서 호출)
+    pub fn receive_library_stats(&mut self) {
+        let Some(rx) = &self.library_stats_rx else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok(Ok(stats)) => self.library_stats = Some(stats),
+            Ok(Err(e)) => self.set_status(format!("Failed to read library stats: {:#}", e)),
+            Err(TryRecvError::Disconnected) => self.set_status("Failed to read library stats"),
+            Err(TryRecvError::Empty) => return,
+        }
+        self.library_stats_rx = None;
+    }
+
+    /// 트랙 정보 업데이트 (폴링)
+    pub fn update(&mut self) {
+        self.apply_snapshot(PlayerSnapshot::fetch());
+    }
+
+    /// 폴링 결과 반영 (트랙 변경 시 챕터/아트워크 등 갱신)
+    pub fn apply_snapshot(&mut self, snapshot: PlayerSnapshot) {
+        // 응답이 없으면 종료로 판단하지 않고 이전 정보를 유지한 채 알림만 표시
+        if snapshot.timed_out {
+            self.set_status("Music.app is not responding");
+            return;
+        }
+
+        // 연속 실패로 Music.app 종료 감지 (화면이 예전 정보로 멈춰 보이지 않도록 안내 표시)
+        if snapshot.track.is_some() {
+            self.track_failures = 0;
+            self.music_running = true;
+        } else {
+            self.track_failures = self.track_failures.saturating_add(1);
+            self.music_running = self.track_failures < MUSIC_FAILURE_THRESHOLD;
+        }
+
+        if let Some(track) = snapshot.track {
+            // 트랙이 변경되었는지 확인
+            let track_changed = track.name != self.last_track_name;
+            let previous = std::mem::replace(&mut self.track, track);
+            self.last_position_instant = Instant::now();
+            let played = self.session_stats.history.len();
+            self.session_stats.observe(&previous, &self.track);
+            // 새로 재생한 곡은 누적 기록에도 추가 (`record_history` 설정 시)
+            if self.config.record_history
+                && let Some(entry) = self.session_stats.history.get(played)
+            {
+                let _ = history::append(entry, self.config.history_max_entries);
+            }
+            
+            // 트랙이 변경되었으면 아트워크 업데이트
+            if track_changed {
+                self.last_track_name = self.track.name.clone();
+                self.marquee_offset = 0;
+                self.marquee_stepped = Instant::now();
+                self.chapters = Vec::new();
+                self.track_loader.fetch_chapters(&self.track.id);
+                self.waveform = waveform_heights(&self.track.name, self.track.duration, WAVEFORM_BARS);
+                self.update_loudness();
+                self.update_artwork();
+                self.write_nowplaying_file();
+            }
+            if let Some(presence) = &mut self.discord {
+                presence.update(&self.track);
+            }
+            self.check_pause_after_current();
+            self.check_autoplay(&previous);
+            if self.mode == AppMode::Lyrics {
+                self.load_lyrics();
+            }
+        }
+        self.advance_slideshow();
+        // Music.app에서 직접 바꿀 수도 있으므로 매번 동기화
+        if let Some(shuffle) = snapshot.shuffle {
+            self.shuffle = shuffle;
+        }
+        if let Some(repeat) = snapshot.repeat {
+            self.repeat = repeat;
+        }
+        if let Some(eq) = snapshot.eq {
+            self.eq = eq;
+        }
+        if let Some(state) = snapshot.volume {
+            self.volume = match self.volume_target {
+                VolumeTarget::App => state.app_volume,
+                VolumeTarget::System => state.system_volume,
+            };
+            self.system_muted = state.system_muted;
+            // 다른 곳에서 볼륨을 올렸으면 음소거 해제된 것으로 처리
+            if self.volume > 0 {
+                self.muted = false;
+            }
+        }
+    }
+
+    /// 로컬 파일 트랙이면 음량 파형 계산 (트랙 ID별로 한 번만)
+    /// ffmpeg 디코딩은 몇 초 걸리므로 백그라운드에서 계산하고 끝나면 `receive_track_data`로 반영합니다.
+    fn update_loudness(&mut self) {
+        if !self.config.progress_waveform || self.track.id.is_empty() || self.loudness.contains_key(&self.track.id) {
+            return;
+        }
+        // 계산하는 동안 같은 트랙을 다시 요청하지 않도록 빈 파형으로 표시
+        self.loudness.insert(self.track.id.clone(), Vec::new());
+        self.track_loader.fetch_loudness(&self.track.id, LOUDNESS_BARS);
+    }
+
+    /// 백그라운드에서 끝난 트랙 부가 정보 반영 (메인 루프에서 호출)
+    pub fn receive_track_data(&mut self) {
+        while let Some(data) = self.track_loader.try_recv() {
+            match data {
+                TrackData::Loudness { track_id, peaks } => {
+                    self.loudness.insert(track_id, peaks);
+                }
+                TrackData::Chapters { track_id, chapters } => {
+                    if track_id == self.track.id {
+                        self.chapters = chapters;
+                    }
+                }
+                TrackData::Lyrics { track_id, lyrics } => {
+                    // 기다리는 사이 곡이 바뀌었으면 새 곡 가사를 다시 요청하므로 버림
+                    if track_id != self.lyrics_track_id {
+                        continue;
+                    }
+                    self.lyrics_loading = false;
+                    self.lyrics = match lyrics {
+                        Ok(text) => Lyrics::parse(&text.unwrap_or_default()),
+                        Err(_) => {
+                            self.set_status("Failed to read lyrics");
+                            Lyrics::default()
+                        }
+                    };
+                }
+                TrackData::Autoplay { artist, queued } => match queued {
+                    Ok(0) => self.set_status("Autoplay: no similar tracks found"),
+                    Ok(count) => self.set_status(format!("Autoplay: queued {count} tracks like {artist}")),
+                    Err(_) => self.set_status("Autoplay failed"),
+                },
+                TrackData::UpNext { up_next } => {
+                    self.up_next_loading = false;
+                    self.up_next = up_next.ok();
+                    self.up_next_index = 0;
+                }
+                TrackData::RestOfAlbum { position, queued } => match queued {
+                    Ok(RestOfAlbum::NotFound) => self.set_status("Current track not found in library album"),
+                    Ok(RestOfAlbum::LastTrack) => self.set_status("Already on the last track of the album"),
+                    Ok(RestOfAlbum::Queued(rest)) => {
+                        self.seek_to(position);
+                        self.set_status(format!("Queued {} remaining album tracks", rest));
+                    }
+                    Err(_) => self.set_status("Failed to queue album"),
+                },
+                TrackData::SearchQueue { queued } => match queued {
+                    Ok(count) => self.set_status(format!("Playing {} results in order", count)),
+                    Err(_) => self.set_status("Failed to queue search results"),
+                },
+                TrackData::AlbumPlay { played } => match played {
+                    Ok(0) => self.set_status("Album not in library, playing track only"),
+                    Ok(count) => self.set_status(format!("Playing album ({} tracks)", count)),
+                    Err(_) => self.set_status("Failed to play album"),
+                },
+            }
+        }
+    }
+
+    /// 현재 트랙의 음량 파형 (없으면 None)
+    pub fn current_loudness(&self) -> Option<&[f32]> {
+        if !self.config.progress_waveform {
+            return None;
+        }
+        self.loudness.get(&self.track.id).map(Vec::as_slice).filter(|peaks| !peaks.is_empty())
+    }
+
+    /// 아트워크 업데이트
+    /// 트랙에 내장된 아트워크를 우선 사용하고, 없을 때만 iTunes API 검색
+    /// 검색과 다운로드는 백그라운드에서 하고 끝나면 `receive_artwork`로 반영합니다.
+    fn update_artwork(&mut self) {
+        self.remove_artwork_files();
+        self.artwork = None;
+        self.artwork_candidates.clear();
+        self.artwork_index = 0;
+        self.slideshow_changed = Instant::now();
+
+        // 같은 앨범 아트워크를 이미 받아 두었으면 바로 표시 (디스크에서 디코딩만)
+        if self.picker_ready
+            && let Some(image) = album_key(&self.track)
+                .and_then(|key| self.artwork_cache.get(&key))
+                .and_then(|path| artwork::decode(path))
+        {
+            self.artwork = Some(self.picker.new_resize_protocol(image));
+            self.artwork_loading = false;
+            return;
+        }
+        self.artwork_loading = true;
+        self.artwork_loader.fetch_track(&self.track, ARTWORK_CANDIDATES);
+    }
+
+    /// 포커스 모드 전환
+    pub fn toggle_focus(&mut self) {
+        self.layout = match self.layout {
+            LayoutMode::Focus => LayoutMode::Full,
+            LayoutMode::Full | LayoutMode::Compact => LayoutMode::Focus,
+        };
+        self.slideshow_changed = Instant::now();
+    }
+
+    /// 한 줄 레이아웃 전환
+    pub fn toggle_compact(&mut self) {
+        self.layout = match self.layout {
+            LayoutMode::Compact => LayoutMode::Full,
+            LayoutMode::Full | LayoutMode::Focus => LayoutMode::Compact,
+        };
+    }
+
+    /// 아트워크 슬라이드쇼 전환 (포커스 모드에서만 동작)
+    pub fn toggle_slideshow(&mut self) {
+        self.slideshow = !self.slideshow;
+        self.slideshow_changed = Instant::now();
+        self.set_status(if self.slideshow { "Artwork slideshow: on" } else { "Artwork slideshow: off" });
+    }
+
+    /// 슬라이드쇼 간격이 지났으면 다음 아트워크 후보로 전환
+    fn advance_slideshow(&mut self) {
+        let interval = Duration::from_secs(self.config.slideshow_interval.max(1));
+        if !self.slideshow
+            || self.layout != LayoutMode::Focus
+            || self.artwork_candidates.len() < 2
+            || self.slideshow_changed.elapsed() < interval
+        {
+            return;
+        }
+
+        self.artwork_index = (self.artwork_index + 1) % self.artwork_candidates.len();
+        self.load_artwork_candidate();
+        self.slideshow_changed = Instant::now();
+    }
+
+    /// 다음 아트워크 후보로 전환 (자동 매칭이 틀렸을 때 수동 보정용)
+    pub fn cycle_artwork(&mut self) {
+        if self.artwork_candidates.len() < 2 {
+            self.set_status("No other artwork matches");
+            return;
+        }
+
+        self.artwork_index = (self.artwork_index + 1) % self.artwork_candidates.len();
+        self.load_artwork_candidate();
+        self.set_status(format!("Artwork {}/{}", self.artwork_index + 1, self.artwork_candidates.len()));
+    }
+
+    /// 현재 인덱스의 아트워크 후보 로드 (백그라운드, 끝날 때까지 이전 이미지 유지)
+    fn load_artwork_candidate(&mut self) {
+        if let Some(url) = self.artwork_candidates.get(self.artwork_index) {
+            self.artwork_loader.fetch_candidate(&self.track, url, self.artwork_index);
+        }
+    }
+
+    /// 백그라운드에서 끝난 아트워크 반영 (메인 루프에서 호출)
+    /// 첫 화면을 그리기 전에는 터미널 그래픽스 질의와 섞이지 않도록 기다립니다.
+    pub fn receive_artwork(&mut self) {
+        if !self.picker_ready {
+            return;
+        }
+
+        while let Some(loaded) = self.artwork_loader.try_recv() {
+            // 그 사이 곡이 바뀌었으면 임시 파일만 정리
+            if loaded.track_id != self.track.id {
+                if let Some(path) = loaded.path {
+                    let _ = std::fs::remove_file(path);
+                }
+                continue;
+            }
+
+            if let Some(path) = loaded.path {
+                // 새로 찾은 첫 번째 후보는 앨범 캐시에 보관 (트랙이 바뀌어도 삭제하지 않음)
+                if loaded.candidates.is_some()
+                    && loaded.image.is_some()
+                    && let Some(key) = album_key(&self.track)
+                {
+                    if let Some(old) = self.artwork_cache.insert(key, path.clone())
+                        && old != path
+                    {
+                        let _ = std::fs::remove_file(old);
+                    }
+                } else if !self.artwork_files.contains(&path) {
+                    self.artwork_files.push(path);
+                }
+            }
+            if loaded.timed_out {
+                self.set_status("Artwork request timed out");
+            }
+            match loaded.candidates {
+                Some(candidates) => {
+                    self.artwork_candidates = candidates;
+                    self.artwork_loading = false;
+                }
+                // 더 최근에 다른 후보로 전환했으면 이 결과는 표시하지 않음
+                None if loaded.index != self.artwork_index => continue,
+                None => {}
+            }
+            if let Some(image) = loaded.image {
+                self.artwork = Some(self.picker.new_resize_protocol(image));
+            }
+        }
+    }
+
+    /// 아트워크 임시 파일 삭제 (트랙 변경 시, 종료 시)
+    pub fn remove_artwork_files(&mut self) {
+        for path in self.artwork_files.drain(..) {
+            // 다른 곡이 캐시로 같은 파일을 쓰고 있으면 유지
+            if !self.artwork_cache.values().any(|cached| *cached == path) {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+
+    /// 앨범 아트워크 캐시 파일 삭제 (종료 시)
+    pub fn clear_artwork_cache(&mut self) {
+        for (_, path) in self.artwork_cache.drain() {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    /// 첫 화면을 그린 뒤 호출: 이후부터 아트워크 반영
+    pub fn mark_picker_ready(&mut self) {
+        self.picker_ready = true;
+    }
+
+    /// 앱 종료
+    pub fn quit(&mut self) {
+        self.running = false;
+    }
+
+    /// 종료 요청 (`confirm_quit` 설정 시 확인 팝업)
+    pub fn request_quit(&mut self) {
+        if self.config.confirm_quit {
+            self.confirm("Quit Apple Music TUI?", Action::ForceQuit);
+        } else {
+            self.quit();
+        }
+    }
+
+    /// 확인 팝업 열기
+    pub fn confirm(&mut self, message: impl Into<String>, on_confirm: Action) {
+        self.confirm_return_mode = std::mem::take(&mut self.mode);
+        self.mode = AppMode::Confirm { message: message.into(), on_confirm };
+    }
+
+    /// 확인 팝업 응답 처리 (수락 시 동작 실행)
+    pub fn resolve_confirm(&mut self, accepted: bool) {
+        let AppMode::Confirm { on_confirm, .. } = self.mode else {
+            return;
+        };
+        self.mode = std::mem::take(&mut self.confirm_return_mode);
+        if accepted {
+            self.perform(on_confirm);
+        }
+    }
+
+    /// 동작 실행
+    pub fn perform(&mut self, action: Action) {
+        match action {
+            Action::PlayPause => self.toggle_play_pause(),
+            Action::PreviousTrack => self.previous_track(),
+            Action::NextTrack => self.next_track(),
+            Action::PreviousChapter => self.previous_chapter(),
+            Action::NextChapter => self.next_chapter(),
+            Action::SeekBackward => self.seek_by(-SEEK_STEP),
+            Action::SeekForward => self.seek_by(SEEK_STEP),
+            Action::SeekBackwardLong => self.seek_by(-SEEK_STEP_LONG),
+            Action::SeekForwardLong => self.seek_by(SEEK_STEP_LONG),
+            Action::VolumeUp => self.volume_up(),
+            Action::VolumeDown => self.volume_down(),
+            Action::SeekPercentPrefix => self.seek_prefix = true,
+            Action::VolumeUpFine => self.adjust_volume(1),
+            Action::VolumeDownFine => self.adjust_volume(-1),
+            Action::Search => self.open_search(),
+            Action::CycleArtwork => self.cycle_artwork(),
+            Action::ToggleTitleNowPlaying => self.toggle_title_now_playing(),
+            Action::ToggleShowRemaining => self.toggle_show_remaining(),
+            Action::ToggleVolumeTarget => self.toggle_volume_target(),
+            Action::Duck => self.toggle_duck(),
+            Action::ToggleMute => self.toggle_mute(),
+            Action::Rate(stars) => self.rate_current_track(stars),
+            Action::ToggleLoved => self.toggle_loved(),
+            Action::ToggleFocus => self.toggle_focus(),
+            Action::ToggleCompact => self.toggle_compact(),
+            Action::ToggleSlideshow => self.toggle_slideshow(),
+            Action::CycleProfile => self.cycle_profile(),
+            Action::CycleTheme => self.cycle_theme(),
+            Action::CycleVolumeStep => self.cycle_volume_step(),
+            Action::PauseAfterCurrent => self.toggle_pause_after_current(),
+            Action::ToggleAutoplay => self.toggle_autoplay(),
+            Action::ToggleShuffle => self.toggle_shuffle(),
+            Action::CycleRepeat => self.cycle_repeat(),
+            Action::QueueRestOfAlbum => self.queue_rest_of_album(),
+            Action::SkipAndRemember => self.skip_and_remember(),
+            Action::OpenSkipLog => self.open_skip_log(),
+            Action::OpenAirPlay => self.open_airplay(),
+            Action::OpenSessionStats => self.mode = AppMode::SessionStats,
+            Action::OpenLibraryStats => self.open_library_stats(),
+            Action::OpenLyrics => self.open_lyrics(),
+            Action::OpenBrowser => self.open_browser(),
+            Action::OpenUpNext => self.open_up_next(),
+            Action::OpenPlaylists => self.open_playlists(),
+            Action::OpenEqualizer => self.open_equalizer(),
+            Action::OpenCommand => self.open_command(),
+            Action::ExportHistory => self.export_session_history(),
+            Action::ToggleHelp => self.toggle_help(),
+            Action::RelaunchMusic => self.relaunch_music(),
+            Action::Refresh => {
+                self.update();
+                self.set_status("Refreshed");
+            }
+            Action::Quit => self.request_quit(),
+            Action::ForceQuit => self.quit(),
+            Action::ClearSkipLog => {
+                self.skip_log.entries.clear();
+                self.skip_log_index = 0;
+                match self.skip_log.save() {
+                    Ok(()) => self.set_status("Skip log cleared"),
+                    Err(_) => self.set_status("Failed to save skip log"),
+                }
+            }
+        }
+    }
+
+    /// 재생 중인지 확인
+    #[allow(dead_code)]
+    pub fn is_playing(&self) -> bool {
+        self.track.state == PlayerState::Playing
+    }
+
+    /// 모드 전환 (검색 관련 상태 보존/초기화를 한곳에서 처리)
+    /// - 결과 → 입력: 검색어를 유지하고 선택했던 결과를 기억 (검색어 다듬기)
+    /// - 그 외 → 입력: 새 검색이므로 검색어 초기화
+    /// - 입력 → 결과: 기억한 결과가 새 결과에도 있으면 다시 선택
+    /// - 검색 → 기본: 검색 상태 전체 초기화
+    pub fn set_mode(&mut self, mode: AppMode) {
+        let from_search = matches!(
+            self.mode,
+            AppMode::SearchInput | AppMode::SearchResults | AppMode::SearchFilter
+        );
+
+        match mode {
+            AppMode::SearchInput => {
+                if matches!(self.mode, AppMode::SearchResults | AppMode::SearchFilter) {
+                    self.search_refine_id = self.search_results.get(self.search_result_index).map(|r| r.id.clone());
+                } else {
+                    self.search_query.clear();
+                    self.search_refine_id = None;
+                    self.search_performed = false;
+                }
+            }
+            AppMode::SearchResults if self.mode == AppMode::SearchInput => {
+                self.search_result_index = self.search_refine_id
+                    .take()
+                    .and_then(|id| self.search_results.iter().position(|r| r.id == id))
+                    .unwrap_or(0);
+            }
+            AppMode::Normal if from_search => {
+                self.search_query.clear();
+                self.search_results.clear();
+                self.search_results_all.clear();
+                self.search_filter = None;
+                self.search_refine_id = None;
+                self.pending_search = None;
+                self.search_performed = false;
+            }
+            _ => {}
+        }
+
+        self.mode = mode;
+    }
+
+    /// 검색 입력 열기
+    pub fn open_search(&mut self) {
+        self.set_mode(AppMode::SearchInput);
+    }
+
+    /// 검색 수행 (Enter: 백그라운드에서 검색하고 끝나면 결과 목록으로 이동)
+    pub fn perform_search(&mut self) {
+        self.search_edited = None;
+        self.start_search(true);
+    }
+
+    /// 검색어가 바뀌었을 때 호출 (잠시 뒤 자동 검색)
+    pub fn search_input_changed(&mut self) {
+        self.search_edited = Some(Instant::now());
+        self.search_performed = false;
+    }
+
+    /// `MARQUEE_STEP`마다 마퀴 위치 한 칸 이동 (메인 루프에서 호출)
+    pub fn tick_marquee(&mut self) {
+        if self.marquee_stepped.elapsed() >= MARQUEE_STEP {
+            self.marquee_offset = self.marquee_offset.wrapping_add(1);
+            self.marquee_stepped = Instant::now();
+        }
+    }
+
+    /// 재생 중이면 `SPECTRUM_STEP`마다 막대 애니메이션 한 프레임 진행 (일시정지 중에는 멈춤)
+    pub fn tick_spectrum(&mut self) {
+        if self.track.state == PlayerState::Playing && self.spectrum_stepped.elapsed() >= SPECTRUM_STEP {
+            self.spectrum_tick = self.spectrum_tick.wrapping_add(1);
+            self.spectrum_stepped = Instant::now();
+        }
+    }
+
+    /// 입력을 멈춘 지 `SEARCH_DEBOUNCE`가 지났으면 입력창을 유지한 채 결과 미리 보기 (메인 루프에서 호출)
+    pub fn check_search_debounce(&mut self) {
+        if self.mode != AppMode::SearchInput {
+            self.search_edited = None;
+            return;
+        }
+        if self.search_edited.is_none_or(|edited| edited.elapsed() < SEARCH_DEBOUNCE) {
+            return;
+        }
+        self.search_edited = None;
+
+        if self.search_query.chars().count() < LIVE_SEARCH_MIN_CHARS {
+            self.pending_search = None;
+            self.set_search_results(Vec::new());
+            return;
+        }
+        self.start_search(false);
+    }
+
+    /// 검색 결과 저장 (필터/선택 초기화)
+    fn set_search_results(&mut self, mut results: Vec<SearchResult>) {
+        if self.config.dedupe_results {
+            results = dedupe_results(results);
+        }
+        self.search_results = results.clone();
+        self.search_results_all = results;
+        self.search_filter = None;
+        self.search_result_index = 0;
+    }
+
+    /// 현재 검색어로 백그라운드 검색 시작 (진행 중인 이전 검색의 결과는 버림)
+    fn start_search(&mut self, open_results: bool) {
+        let id = self.searcher.start(
+            self.search_mode,
+            &self.search_query,
+            self.search_playlist.as_ref().map(|playlist| playlist.id.as_str()),
+            self.config.fuzzy_search,
+        );
+        self.pending_search = Some(PendingSearch { id, started: Instant::now(), open_results });
+    }
+
+    /// 백그라운드에서 끝난 검색 결과 반영 (메인 루프에서 호출)
+    /// 취소했거나 더 새로운 검색이 시작된 뒤 도착한 결과는 버립니다.
+    pub fn receive_search_results(&mut self) {
+        while let Some(outcome) = self.searcher.try_recv() {
+            let Some(pending) = self.pending_search.filter(|pending| pending.id == outcome.id) else {
+                continue;
+            };
+            self.pending_search = None;
+            if let Some(status) = outcome.status {
+                self.set_status(status);
+            }
+            // 미리 보기 검색 중 검색어가 바뀌었으면 이전 검색어의 결과는 버림
+            let Some(results) = outcome.results.filter(|_| outcome.query == self.search_query) else {
+                continue;
+            };
+            self.set_search_results(results);
+            self.search_performed = true;
+            if pending.open_results && self.mode == AppMode::SearchInput && !self.search_results.is_empty() {
+                self.set_mode(AppMode::SearchResults);
+            }
+        }
+    }
+
+    /// 검색 중이면 입력창에 표시할 스피너 문자
+    pub fn search_spinner(&self) -> Option<char> {
+        let pending = self.pending_search?;
+        let frame = pending.started.elapsed().as_millis() / SPINNER_STEP.as_millis();
+        SPINNER_FRAMES.chars().nth(frame as usize % SPINNER_FRAMES.chars().count())
+    }
+
+    /// 검색 소스 전환 (보관함 → Apple Music → 둘 다)
+    pub fn toggle_search_mode(&mut self) {
+        self.search_mode = match self.search_mode {
+            SearchMode::Library => SearchMode::AppleMusic,
+            SearchMode::AppleMusic => SearchMode::Combined,
+            SearchMode::Combined => SearchMode::Library,
+        };
+    }
+
+    /// 검색 결과 선택 및 재생
+    /// `search_auto_advance` 설정이 켜져 있으면 이후 결과들도 이어서 재생
+    pub fn search_play_selection(&mut self) {
+        if self.config.search_auto_advance {
+            self.search_play_from_selection();
+        } else if let Some(result) = self.search_results.get(self.search_result_index) {
+            let _ = jxa::play_track_by_id(&result.id);
+            self.close_search();
+        }
+    }
+
+    /// 선택한 결과부터 나머지 검색 결과를 순서대로 재생
+    /// Apple Music 결과는 큐를 만들 수 없으므로 선택한 곡만 재생
+    pub fn search_play_from_selection(&mut self) {
+        if self.search_result_index >= self.search_results.len() {
+            return;
+        }
+
+        if self.search_results[self.search_result_index].source == ResultSource::AppleMusic {
+            let _ = jxa::play_track_by_id(&self.search_results[self.search_result_index].id);
+        } else {
+            // 함께 검색한 경우 큐에는 보관함 결과만 넣음
+            let ids: Vec<String> = self.search_results[self.search_result_index..]
+                .iter()
+                .filter(|result| result.source == ResultSource::Library)
+                .map(|result| result.id.clone())
+                .collect();
+            self.track_loader.play_search_queue(ids);
+            self.set_status("Queueing search results…");
+        }
+        self.close_search();
+    }
+
+    /// 선택한 결과를 현재 곡 다음에 재생되도록 추가 (검색 결과는 계속 열어 둠)
+    pub fn search_queue_next(&mut self) {
+        let Some(result) = self.search_results.get(self.search_result_index) else {
+            return;
+        };
+        if result.source == ResultSource::AppleMusic {
+            self.set_status("Only library tracks can be queued");
+            return;
+        }
+
+        let name = result.name.clone();
+        match jxa::queue_next(&result.id) {
+            Ok(QueueNext::Queued) => self.set_status(format!("Queued next: {}", name)),
+            Ok(QueueNext::NotFound) => self.set_status("Track not found in library"),
+            Ok(QueueNext::Unsupported) => self.set_status("Play next only works while playing a queue from search"),
+            Err(_) => self.set_status("Failed to queue track"),
+        }
+    }
+
+    /// 선택한 결과가 속한 앨범 전체 재생 (보관함에 앨범이 없으면 선택한 곡만 재생)
+    pub fn search_play_album(&mut self) {
+        let Some(result) = self.search_results.get(self.search_result_index) else {
+            return;
+        };
+        if result.album.is_empty() {
+            self.search_play_selection();
+            return;
+        }
+
+        self.track_loader.play_album(&result.artist, &result.album, &result.id);
+        self.close_search();
+        self.set_status("Loading album…");
+    }
+
+    /// 검색 종료 후 기본 모드로 복귀 (진행 중인 검색은 결과를 기다리지 않고 버림)
+    pub fn close_search(&mut self) {
+        if self.pending_search.is_some() {
+            self.set_status("Search canceled");
+        }
+        self.set_mode(AppMode::Normal);
+    }
+
+    /// 검색 결과 필터 입력 열기
+    pub fn open_search_filter(&mut self) {
+        self.search_filter_input.clear();
+        self.mode = AppMode::SearchFilter;
+    }
+
+    /// 입력한 필터를 검색 결과에 적용 (빈 입력이면 필터 해제)
+    pub fn apply_search_filter(&mut self) {
+        if self.search_filter_input.trim().is_empty() {
+            self.search_filter = None;
+        } else if let Some(filter) = ResultFilter::parse(&self.search_filter_input) {
+            self.search_filter = Some(filter);
+        } else {
+            self.set_status("Invalid filter (e.g. 2-4, 2:30-4:15, 120-140bpm)");
+            return;
+        }
+
+        self.search_results = match self.search_filter {
+            Some(filter) => self.search_results_all.iter().filter(|r| filter.matches(r)).cloned().collect(),
+            None => self.search_results_all.clone(),
+        };
+        self.search_result_index = 0;
+        self.mode = AppMode::SearchResults;
+    }
+
+    /// 검색 결과 선택 위로 이동
+    pub fn search_select_prev(&mut self) {
+        if self.search_result_index > 0 {
+            self.search_result_index -= 1;
+        }
+    }
+
+    /// 검색 결과 선택 아래로 이동
+    pub fn search_select_next(&mut self) {
+        if self.search_result_index < self.search_results.len().saturating_sub(1) {
+            self.search_result_index += 1;
+        }
+    }
+
+    /// 검색 결과 선택을 한 페이지 위로 이동
+    pub fn search_select_page_up(&mut self) {
+        self.search_result_index = self.search_result_index.saturating_sub(self.list_page_height());
+    }
+
+    /// 검색 결과 선택을 한 페이지 아래로 이동
+    pub fn search_select_page_down(&mut self) {
+        let last = self.search_results.len().saturating_sub(1);
+        self.search_result_index = (self.search_result_index + self.list_page_height()).min(last);
+    }
+
+    /// 첫 번째 검색 결과 선택
+    pub fn search_select_first(&mut self) {
+        self.search_result_index = 0;
+    }
+
+    /// 지정한 검색 결과 선택 (범위를 넘으면 마지막 결과)
+    pub fn search_select_index(&mut self, index: usize) {
+        self.search_result_index = index.min(self.search_results.len().saturating_sub(1));
+    }
+
+    /// vim식 숫자 접두사에 한 자리 추가 (예: `1`, `2` → 12)
+    pub fn push_count_digit(&mut self, digit: u32) {
+        let count = self.pending_count.unwrap_or(0).saturating_mul(10).saturating_add(digit as usize);
+        self.pending_count = Some(count.min(MAX_PENDING_COUNT));
+    }
+
+    /// 숫자 접두사를 입력하는 중인지 (`0`을 접두사의 일부로 받을지 판단)
+    pub fn has_pending_count(&self) -> bool {
+        self.pending_count.is_some()
+    }
+
+    /// 입력한 숫자 접두사를 가져오고 대기 중인 `g`와 함께 초기화
+    pub fn take_pending_count(&mut self) -> Option<usize> {
+        self.pending_g = false;
+        self.pending_count.take()
+    }
+
+    /// `g` 입력: 두 번 누르면(`gg`) 첫 번째 결과, 숫자 접두사가 있으면 그 번호의 결과 선택
+    pub fn search_press_g(&mut self) {
+        if !self.pending_g {
+            self.pending_g = true;
+            return;
+        }
+        let target = self.take_pending_count().map_or(0, |count| count.saturating_sub(1));
+        self.search_select_index(target);
+    }
+
+    /// 마지막 검색 결과 선택
+    pub fn search_select_last(&mut self) {
+        self.search_result_index = self.search_results.len().saturating_sub(1);
+    }
+
+    /// 마지막으로 그린 팝업 목록에 한 번에 보이는 항목 수 (테두리 제외, 최소 1)
+    fn list_page_height(&self) -> usize {
+        self.list_hit.map_or(1, |hit| hit.area.height.saturating_sub(2).max(1) as usize)
+    }
+
+    /// 현재 모드 목록의 항목 수
+    fn list_len(&self) -> usize {
+        match self.mode {
+            AppMode::SearchResults => self.search_results.len(),
+            AppMode::SkipLog => self.skip_log.entries.len(),
+            AppMode::AirPlay => self.airplay_devices.len(),
+            AppMode::Browser => self.browser.current().items.len(),
+            AppMode::UpNext => self.up_next.as_ref().map_or(0, |up_next| up_next.tracks.len()),
+            AppMode::Playlists => self.playlists.len(),
+            AppMode::Equalizer => self.eq_presets.len(),
+            _ => 0,
+        }
+    }
+
+    /// 현재 모드 목록에서 항목 선택
+    fn list_select(&mut self, index: usize) {
+        match self.mode {
+            AppMode::SearchResults => self.search_result_index = index,
+            AppMode::SkipLog => self.skip_log_index = index,
+            AppMode::AirPlay => self.airplay_index = index,
+            AppMode::Browser => self.browser.select(index),
+            AppMode::UpNext => self.up_next_index = index,
+            AppMode::Playlists => self.playlist_index = index,
+            AppMode::Equalizer => self.eq_index = index,
+            _ => {}
+        }
+    }
+
+    /// 현재 모드 목록 선택 위로 이동 (마우스 휠)
+    pub fn list_select_prev(&mut self) {
+        match self.mode {
+            AppMode::SearchResults => self.search_select_prev(),
+            AppMode::SkipLog => self.skip_log_select_prev(),
+            AppMode::AirPlay => self.airplay_select_prev(),
+            AppMode::Browser => self.browser.select_prev(),
+            AppMode::UpNext => self.up_next_select_prev(),
+            AppMode::Playlists => self.playlists_select_prev(),
+            AppMode::Equalizer => self.eq_select_prev(),
+            _ => {}
+        }
+    }
+
+    /// 현재 모드 목록 선택 아래로 이동 (마우스 휠)
+    pub fn list_select_next(&mut self) {
+        match self.mode {
+            AppMode::SearchResults => self.search_select_next(),
+            AppMode::SkipLog => self.skip_log_select_next(),
+            AppMode::AirPlay => self.airplay_select_next(),
+            AppMode::Browser => self.browser.select_next(),
+            AppMode::UpNext => self.up_next_select_next(),
+            AppMode::Playlists => self.playlists_select_next(),
+            AppMode::Equalizer => self.eq_select_next(),
+            _ => {}
+        }
+    }
+
+    /// 진행/볼륨 바 클릭 처리: 클릭한 가로 위치 비율로 이동하거나 볼륨 설정
+    pub fn click_gauge(&mut self, column: u16, row: u16) {
+        let position = ratatui::layout::Position::new(column, row);
+        // 맨 왼쪽 칸은 0, 맨 오른쪽 칸은 끝
+        let fraction = |rect: Rect| (column - rect.x) as f64 / rect.width.saturating_sub(1).max(1) as f64;
+
+        if let Some(rect) = self.progress_hit
+            && rect.contains(position)
+        {
+            if !self.track.id.is_empty() {
+                self.seek_to(fraction(rect) * self.track.duration);
+            }
+        } else if let Some(rect) = self.volume_hit
+            && rect.contains(position)
+        {
+            self.set_volume((fraction(rect) * 100.0).round() as u8);
+        }
+    }
+
+    /// 재생 버튼 클릭 처리
+    pub fn click_button(&mut self, column: u16, row: u16) {
+        let position = ratatui::layout::Position::new(column, row);
+        if let Some(&(_, action)) = self.button_hits.iter().find(|(rect, _)| rect.contains(position)) {
+            self.perform(action);
+        }
+    }
+
+    /// 목록 클릭 처리: 한 번 클릭하면 선택, 같은 행을 더블 클릭하면 실행
+    pub fn click_list(&mut self, column: u16, row: u16) {
+        let Some(hit) = self.list_hit else {
+            return;
+        };
+
+        // 테두리 안쪽 행만 항목으로 취급
+        let area = hit.area;
+        let inside = column > area.x
+            && column < area.right().saturating_sub(1)
+            && row > area.y
+            && row < area.bottom().saturating_sub(1);
+        if !inside {
+            return;
+        }
+
+        let index = hit.offset + (row - area.y - 1) as usize;
+        if index >= self.list_len() {
+            return;
+        }
+        self.list_select(index);
+
+        let double = matches!(self.last_click, Some((last, at)) if last == index && at.elapsed() <= DOUBLE_CLICK_INTERVAL);
+        if double {
+            self.last_click = None;
+            match self.mode {
+                AppMode::SearchResults => self.search_play_selection(),
+                AppMode::AirPlay => self.airplay_toggle_selected(),
+                AppMode::Browser => self.browser_enter(),
+                AppMode::Playlists => self.playlists_play_selection(),
+                AppMode::Equalizer => self.eq_apply_selection(),
+                _ => {}
+            }
+        } else {
+            self.last_click = Some((index, Instant::now()));
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! 아트워크 백그라운드 로드 모듈
+//! 내장 아트워크 추출, iTunes 검색, 다운로드, 디코딩은 수백 ms에서 몇 초까지 걸리므로
+//! 별도 스레드에서 처리하고 결과를 채널로 App에 돌려줍니다.
+
+use crate::jxa::{self, TrackInfo};
+use image::{DynamicImage, ImageReader};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+
+/// 아트워크 후보 중 Music.app 내장 아트워크 파일을 나타내는 접두사
+const EMBEDDED_ARTWORK_PREFIX: &str = "file://";
+
+/// 백그라운드 로드 결과
+pub struct LoadedArtwork {
+    /// 요청한 트랙 ID (그 사이 곡이 바뀌었으면 버림)
+    pub track_id: String,
+    /// 새로 찾은 후보 목록 (이미 찾은 후보 중 하나만 로드한 경우 None)
+    pub candidates: Option<Vec<String>>,
+    /// 로드한 후보 인덱스
+    pub index: usize,
+    /// 저장한 임시 파일 (종료나 곡 변경 시 삭제)
+    pub path: Option<PathBuf>,
+    /// 디코딩한 이미지 (후보가 없거나 실패하면 None)
+    pub image: Option<DynamicImage>,
+    /// iTunes 요청이 재시도 후에도 시간 초과로 실패
+    pub timed_out: bool,
+}
+
+/// 아트워크 로더 (요청마다 작업 스레드 하나)
+pub struct ArtworkLoader {
+    tx: Sender<LoadedArtwork>,
+    rx: Receiver<LoadedArtwork>,
+}
+
+impl Default for ArtworkLoader {
+    fn default() -> Self {
+        let (tx, rx) = mpsc::channel();
+        ArtworkLoader { tx, rx }
+    }
+}
+
+impl ArtworkLoader {
+    /// 트랙의 후보를 찾아 첫 번째 후보 로드 (내장 아트워크 우선, 없으면 iTunes에서 `limit`개)
+    pub fn fetch_track(&self, track: &TrackInfo, limit: usize) {
+        let track = track.clone();
+        let tx = self.tx.clone();
+        std::thread::spawn(move || {
+            let mut timed_out = false;
+            let candidates = match jxa::get_embedded_artwork(&track) {
+                Ok(Some(path)) => vec![format!("{}{}", EMBEDDED_ARTWORK_PREFIX, path.display())],
+                _ => jxa::get_artwork_urls(&track, limit).unwrap_or_else(|e| {
+                    timed_out = e.is::<jxa::HttpTimeout>();
+                    Vec::new()
+                }),
+            };
+            let path = candidates.first().and_then(|url| candidate_path(url, &track));
+            let _ = tx.send(LoadedArtwork {
+                track_id: track.id,
+                image: path.as_deref().and_then(decode),
+                candidates: Some(candidates),
+                index: 0,
+                path,
+                timed_out,
+            });
+        });
+    }
+
+    /// 이미 찾은 후보 중 하나 로드 (수동 전환, 슬라이드쇼)
+    pub fn fetch_candidate(&self, track: &TrackInfo, url: &str, index: usize) {
+        let track = track.clone();
+        let url = url.to_string();
+        let tx = self.tx.clone();
+        std::thread::spawn(move || {
+            let path = candidate_path(&url, &track);
+            let _ = tx.send(LoadedArtwork {
+                track_id: track.id,
+                candidates: None,
+                index,
+                image: path.as_deref().and_then(decode),
+                path,
+                timed_out: false,
+            });
+        });
+    }
+
+    /// 끝난 로드 결과 하나 가져오기 (없으면 None)
+    pub fn try_recv(&self) -> Option<LoadedArtwork> {
+        self.rx.try_recv().ok()
+    }
+}
+
+/// 후보의 이미지 파일 경로 (내장 아트워크는 저장된 파일, URL이면 다운로드)
+fn candidate_path(url: &str, track: &TrackInfo) -> Option<PathBuf> {
+    match url.strip_prefix(EMBEDDED_ARTWORK_PREFIX) {
+        Some(path) => Some(PathBuf::from(path)),
+        None => jxa::download_artwork(url, track).ok().flatten(),
+    }
+}
+
+/// 이미지 디코딩 (내장 아트워크는 PNG일 수도 있으므로 확장자 대신 내용으로 형식 판단)
+pub fn decode(path: &Path) -> Option<DynamicImage> {
+    ImageReader::open(path).ok()?.with_guessed_format().ok()?.decode().ok()
+}
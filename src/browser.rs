@@ -0,0 +1,174 @@
+//! 라이브러리 브라우저 모듈
+//! 플레이리스트 → 트랙, 아티스트 → 앨범 → 트랙 순으로 보관함을 탐색하는 상태를 관리합니다.
+
+use crate::jxa::{self, Playlist, SearchResult};
+use anyhow::Result;
+
+/// 브라우저 단계 종류
+#[derive(Debug, Clone, PartialEq)]
+pub enum BrowserLevelKind {
+    /// 최상위 (Playlists / Artists)
+    Root,
+    Playlists,
+    PlaylistTracks { id: String, name: String },
+    Artists,
+    Albums { artist: String },
+    AlbumTracks { artist: String, album: String },
+}
+
+/// 브라우저 한 단계 (화면의 한 열)
+#[derive(Debug, Clone)]
+pub struct BrowserLevel {
+    pub kind: BrowserLevelKind,
+    /// 경로 표시용 이름
+    pub title: String,
+    /// 목록에 표시할 항목
+    pub items: Vec<String>,
+    /// 트랙 단계의 트랙 정보 (`items`와 같은 순서)
+    pub tracks: Vec<SearchResult>,
+    /// 플레이리스트 단계의 플레이리스트 정보 (`items`와 같은 순서)
+    pub playlists: Vec<Playlist>,
+    /// 선택 인덱스
+    pub index: usize,
+}
+
+impl BrowserLevel {
+    fn new(kind: BrowserLevelKind, title: impl Into<String>, items: Vec<String>) -> Self {
+        BrowserLevel { kind, title: title.into(), items, tracks: Vec::new(), playlists: Vec::new(), index: 0 }
+    }
+
+    fn with_playlists(kind: BrowserLevelKind, title: impl Into<String>, playlists: Vec<Playlist>) -> Self {
+        let items = playlists.iter().map(|p| p.name.clone()).collect();
+        BrowserLevel { kind, title: title.into(), items, tracks: Vec::new(), playlists, index: 0 }
+    }
+
+    fn with_tracks(kind: BrowserLevelKind, title: impl Into<String>, tracks: Vec<SearchResult>) -> Self {
+        let items = tracks.iter().map(|t| format!("{} - {}", t.name, t.artist)).collect();
+        BrowserLevel { kind, title: title.into(), items, tracks, playlists: Vec::new(), index: 0 }
+    }
+}
+
+/// 트랙 단계에서 Enter를 눌렀을 때의 재생 요청
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlayRequest {
+    /// 플레이리스트(persistentID)를 해당 위치부터 재생
+    Playlist { id: String, name: String, index: usize },
+    /// 선택한 트랙부터 나머지 트랙을 큐로 재생
+    Queue(Vec<String>),
+}
+
+/// 라이브러리 브라우저 상태 (단계 스택)
+#[derive(Debug, Clone)]
+pub struct Browser {
+    pub stack: Vec<BrowserLevel>,
+}
+
+impl Default for Browser {
+    fn default() -> Self {
+        let root = BrowserLevel::new(
+            BrowserLevelKind::Root,
+            "Library",
+            vec!["Playlists".to_string(), "Artists".to_string()],
+        );
+        Browser { stack: vec![root] }
+    }
+}
+
+impl Browser {
+    /// 현재 (가장 안쪽) 단계
+    pub fn current(&self) -> &BrowserLevel {
+        self.stack.last().expect("브라우저 스택은 비어 있을 수 없음")
+    }
+
+    fn current_mut(&mut self) -> &mut BrowserLevel {
+        self.stack.last_mut().expect("브라우저 스택은 비어 있을 수 없음")
+    }
+
+    /// 현재 경로 (예: "Library › Artists › Radiohead")
+    pub fn breadcrumb(&self) -> String {
+        self.stack.iter().map(|level| level.title.as_str()).collect::<Vec<_>>().join(" › ")
+    }
+
+    /// 선택 위로 이동
+    pub fn select_prev(&mut self) {
+        let level = self.current_mut();
+        level.index = level.index.saturating_sub(1);
+    }
+
+    /// 선택 아래로 이동
+    pub fn select_next(&mut self) {
+        let level = self.current_mut();
+        if level.index < level.items.len().saturating_sub(1) {
+            level.index += 1;
+        }
+    }
+
+    /// 선택 인덱스 지정
+    pub fn select(&mut self, index: usize) {
+        let level = self.current_mut();
+        if index < level.items.len() {
+            level.index = index;
+        }
+    }
+
+    /// 상위 단계로 (최상위면 false)
+    pub fn back(&mut self) -> bool {
+        if self.stack.len() <= 1 {
+            return false;
+        }
+        self.stack.pop();
+        true
+    }
+
+    /// 선택한 항목으로 들어가기
+    /// 트랙 단계에서는 재생 요청을 반환하고, 그 외에는 다음 단계를 불러와 쌓음
+    pub fn enter(&mut self) -> Result<Option<PlayRequest>> {
+        let level = self.current();
+        let Some(selected) = level.items.get(level.index).cloned() else {
+            return Ok(None);
+        };
+        let index = level.index;
+
+        let next = match level.kind.clone() {
+            BrowserLevelKind::Root if index == 0 => {
+                BrowserLevel::with_playlists(BrowserLevelKind::Playlists, selected, jxa::get_playlists()?)
+            }
+            BrowserLevelKind::Root => {
+                BrowserLevel::new(BrowserLevelKind::Artists, selected, jxa::get_library_artists()?)
+            }
+            BrowserLevelKind::Playlists => {
+                // 이름이 같은 플레이리스트가 여러 개일 수 있으므로 persistentID로 찾음
+                let id = level.playlists[index].id.clone();
+                let tracks = jxa::get_playlist_tracks(&id)?;
+                let kind = BrowserLevelKind::PlaylistTracks { id, name: selected.clone() };
+                BrowserLevel::with_tracks(kind, selected, tracks)
+            }
+            BrowserLevelKind::Artists => BrowserLevel::new(
+                BrowserLevelKind::Albums { artist: selected.clone() },
+                selected.clone(),
+                jxa::get_artist_albums(&selected)?,
+            ),
+            BrowserLevelKind::Albums { artist } => {
+                let tracks = jxa::get_album_tracks(&artist, &selected)?;
+                let mut level = BrowserLevel::with_tracks(
+                    BrowserLevelKind::AlbumTracks { artist, album: selected.clone() },
+                    selected,
+                    tracks,
+                );
+                // 앨범 안에서는 아티스트가 같으므로 트랙 번호 + 제목만 표시
+                level.items = level.tracks.iter().enumerate().map(|(i, t)| format!("{:>2}. {}", i + 1, t.name)).collect();
+                level
+            }
+            BrowserLevelKind::PlaylistTracks { id, name } => {
+                return Ok(Some(PlayRequest::Playlist { id, name, index }));
+            }
+            BrowserLevelKind::AlbumTracks { .. } => {
+                let ids = level.tracks[index..].iter().map(|t| t.id.clone()).collect();
+                return Ok(Some(PlayRequest::Queue(ids)));
+            }
+        };
+
+        self.stack.push(next);
+        Ok(None)
+    }
+}
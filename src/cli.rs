@@ -0,0 +1,47 @@
+//! 명령행 인자 파싱 모듈
+
+use anyhow::{bail, Result};
+use std::path::PathBuf;
+
+/// 명령행 인자
+#[derive(Debug, Default)]
+pub struct Args {
+    /// TUI 없이 현재 상태를 두 줄로 출력하고 종료 (`--status`)
+    pub status: bool,
+    /// 라이브러리 브라우저 화면으로 시작 (`--browse`)
+    pub browse: bool,
+    /// 시작 시 적용할 설정 프로필 (`--profile <이름>`)
+    pub profile: Option<String>,
+    /// 누적 재생 기록을 파일로 내보내고 종료 (`--export-history <경로>`, `.csv`면 CSV)
+    pub export_history: Option<PathBuf>,
+    /// 곡이 바뀔 때마다 "아티스트 - 제목"을 쓸 파일 (`--nowplaying-file <경로>`, OBS 텍스트 소스용)
+    pub nowplaying_file: Option<PathBuf>,
+}
+
+impl Args {
+    /// `std::env::args()`에서 인자 파싱
+    pub fn parse() -> Result<Self> {
+        let mut args = Args::default();
+        let mut iter = std::env::args().skip(1);
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--status" => args.status = true,
+                "--browse" => args.browse = true,
+                "--profile" => match iter.next() {
+                    Some(name) => args.profile = Some(name),
+                    None => bail!("--profile 뒤에 프로필 이름이 필요함"),
+                },
+                "--export-history" => match iter.next() {
+                    Some(path) => args.export_history = Some(PathBuf::from(path)),
+                    None => bail!("--export-history 뒤에 파일 경로가 필요함"),
+                },
+                "--nowplaying-file" => match iter.next() {
+                    Some(path) => args.nowplaying_file = Some(PathBuf::from(path)),
+                    None => bail!("--nowplaying-file 뒤에 파일 경로가 필요함"),
+                },
+                _ => bail!("알 수 없는 인자: {}", arg),
+            }
+        }
+        Ok(args)
+    }
+}
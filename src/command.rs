@@ -0,0 +1,109 @@
+//! 텍스트 명령 모듈
+//! 명령 팔레트(`:`)와 FIFO에서 받은 한 줄 명령(`shuffle`, `repeat all`, `volume 80`, `seek 1:30` 등)을
+//! 실행할 명령으로 변환합니다. 키맵에 쓰는 동작 이름(`toggle_focus`, `lyrics` 등)도 그대로 받습니다.
+//! FIFO에서는 화면을 바꾸거나 종료하는 동작을 막고 재생 제어 동작만 받습니다.
+
+use crate::app::Action;
+use crate::jxa::RepeatMode;
+use crate::keymap;
+
+/// 텍스트로 받은 명령
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    Play,
+    Pause,
+    Toggle,
+    Next,
+    Prev,
+    /// 볼륨 설정 (0-100)
+    Volume(u8),
+    /// 재생 위치 이동 (초)
+    Seek(f64),
+    /// 셔플 켜기/끄기
+    Shuffle(bool),
+    /// 반복 모드 설정
+    Repeat(RepeatMode),
+    /// 키맵 동작 실행
+    Action(Action),
+}
+
+impl Command {
+    /// FIFO로 받은 명령 한 줄 파싱 (키맵 동작은 재생 제어만 허용, 알 수 없는 명령이면 None)
+    pub fn parse(line: &str) -> Option<Self> {
+        Self::parse_with(line, is_transport_action)
+    }
+
+    /// 명령 팔레트 입력 파싱 (모든 키맵 동작 허용)
+    pub fn parse_palette(line: &str) -> Option<Self> {
+        Self::parse_with(line, |_| true)
+    }
+
+    /// 명령 한 줄 파싱 (대소문자 무시, `allow_action`이 거부한 키맵 동작은 None)
+    fn parse_with(line: &str, allow_action: fn(Action) -> bool) -> Option<Self> {
+        let line = line.to_lowercase();
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        let command = match words.as_slice() {
+            ["play"] => Command::Play,
+            ["pause"] => Command::Pause,
+            ["toggle"] => Command::Toggle,
+            ["next"] => Command::Next,
+            ["prev" | "previous"] => Command::Prev,
+            ["volume", level] => Command::Volume(level.parse::<u8>().ok()?.min(100)),
+            ["seek", position] => Command::Seek(parse_position(position)?),
+            ["shuffle"] => Command::Action(Action::ToggleShuffle),
+            ["shuffle", "on"] => Command::Shuffle(true),
+            ["shuffle", "off"] => Command::Shuffle(false),
+            ["repeat"] => Command::Action(Action::CycleRepeat),
+            ["repeat", mode @ ("off" | "one" | "all")] => Command::Repeat(RepeatMode::from(*mode)),
+            [] => return None,
+            // 키맵 동작 이름 ("toggle focus"처럼 띄어 써도 인식)
+            words => Command::Action(keymap::action_from_name(&words.join("_")).filter(|action| allow_action(*action))?),
+        };
+        Some(command)
+    }
+}
+
+/// 화면 없이 실행해도 되는 재생 제어 동작인지 (팝업, 입력 모드, 종료 등은 제외)
+fn is_transport_action(action: Action) -> bool {
+    matches!(
+        action,
+        Action::PlayPause
+            | Action::PreviousTrack
+            | Action::NextTrack
+            | Action::PreviousChapter
+            | Action::NextChapter
+            | Action::SeekBackward
+            | Action::SeekForward
+            | Action::SeekBackwardLong
+            | Action::SeekForwardLong
+            | Action::VolumeUp
+            | Action::VolumeDown
+            | Action::VolumeUpFine
+            | Action::VolumeDownFine
+            | Action::Duck
+            | Action::ToggleMute
+            | Action::Rate(_)
+            | Action::ToggleLoved
+            | Action::PauseAfterCurrent
+            | Action::ToggleAutoplay
+            | Action::ToggleShuffle
+            | Action::CycleRepeat
+            | Action::QueueRestOfAlbum
+            | Action::SkipAndRemember
+            | Action::Refresh
+    )
+}
+
+/// 재생 위치 파싱 ("90" → 초, "1:30" → m:ss, "1:02:03" → h:mm:ss)
+fn parse_position(text: &str) -> Option<f64> {
+    let mut seconds = 0.0;
+    for part in text.split(':') {
+        let value: f64 = part.parse().ok()?;
+        if value < 0.0 {
+            return None;
+        }
+        seconds = seconds * 60.0 + value;
+    }
+    Some(seconds)
+}
@@ -0,0 +1,288 @@
+//! 설정 파일 모듈
+//! `~/.config/apple-music-tui/config.toml`에서 사용자 설정을 읽어옵니다.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// 정지 상태에서 재생을 시작할 때의 동작
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StartAction {
+    /// 플레이리스트 목록을 열어 고르기 (취소하면 아무것도 하지 않음)
+    #[default]
+    Pick,
+    /// 라이브러리 첫 번째 곡
+    FirstTrack,
+    /// 라이브러리에서 무작위 곡
+    Random,
+    /// `start_playlist`로 지정한 플레이리스트
+    Playlist,
+    /// 마지막 재생 컨텍스트 이어서 재생
+    Resume,
+}
+
+/// 볼륨 키가 조절하는 대상
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VolumeTarget {
+    /// Music.app 자체 볼륨 (`soundVolume`)
+    #[default]
+    App,
+    /// macOS 시스템 출력 볼륨
+    System,
+}
+
+/// 아트워크 테두리 스타일
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtworkBorder {
+    /// 테두리 없음
+    #[default]
+    None,
+    /// 한 줄
+    Plain,
+    /// 두 줄
+    Double,
+    /// 둥근 모서리
+    Rounded,
+    /// 한 줄 + 오른쪽/아래 그림자
+    Shadow,
+}
+
+/// 아트워크가 없을 때 표시할 대체 이미지
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtworkFallback {
+    /// 재생 중에는 움직이는 막대, 정지 상태면 음표 아이콘과 "No Artwork"
+    #[default]
+    Note,
+    /// 트랙 이름/길이로 만든 고유한 장식용 파형
+    Waveform,
+}
+
+/// `[keys]` 항목 값: 키 하나 또는 여러 개
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum KeyBinding {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyBinding {
+    /// 지정한 키 목록
+    pub fn keys(&self) -> &[String] {
+        match self {
+            KeyBinding::One(key) => std::slice::from_ref(key),
+            KeyBinding::Many(keys) => keys,
+        }
+    }
+}
+
+/// `[colors]` 항목: 테마 색상 개별 지정 (색상 이름 또는 "#rrggbb")
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ThemeColors {
+    pub title: Option<String>,
+    pub progress: Option<String>,
+    pub volume: Option<String>,
+    pub highlight: Option<String>,
+}
+
+/// 이름 붙은 설정 프로필 (지정한 값만 기본 설정을 덮어씀)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    pub volume_step: Option<u8>,
+    pub poll_interval_ms: Option<u64>,
+    pub volume_target: Option<VolumeTarget>,
+    pub output_device: Option<String>,
+    pub artwork_border: Option<ArtworkBorder>,
+    pub dim_when_paused: Option<bool>,
+    pub theme: Option<String>,
+}
+
+/// 사용자 설정
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// 검색 결과 재생 시 이후 결과들을 이어서 재생할지 여부
+    pub search_auto_advance: bool,
+    /// "건너뛰고 기억하기" 시 트랙을 싫어요로 표시할지 여부
+    pub skip_marks_disliked: bool,
+    /// 정지 상태에서 재생 시작 시 동작
+    pub start_action: StartAction,
+    /// `start_action = "playlist"`일 때 재생할 플레이리스트 이름
+    pub start_playlist: Option<String>,
+    /// 정지 상태에서 이전/다음 곡을 누르면 재생 시작 (끄면 안내 메시지만 표시)
+    pub skip_starts_playback: bool,
+    /// 볼륨 키가 조절하는 대상 (`v` 키로 전환 가능)
+    pub volume_target: VolumeTarget,
+    /// 볼륨 키 한 번에 바뀌는 양
+    pub volume_step: u8,
+    /// 아트워크/Apple Music 검색 HTTP 요청 제한 시간 (초)
+    pub http_timeout_secs: u64,
+    /// 스크립트마다 osascript를 새로 실행하지 않고 하나의 프로세스를 계속 사용 (지연 시간/CPU 감소)
+    pub persistent_osascript: bool,
+    /// 상태 폴링 간격 (밀리초)
+    pub poll_interval_ms: u64,
+    /// 시작/프로필 전환 시 선택할 AirPlay 출력 장치 이름
+    pub output_device: Option<String>,
+    /// 볼륨이 100을 넘으면 0으로, 0 아래로 내려가면 100으로 순환 (기본은 제한)
+    pub volume_wrap: bool,
+    /// 덕킹(`d` 키) 시 낮출 볼륨 (0-100)
+    pub duck_volume: u8,
+    /// 종료 전에 확인 팝업 표시
+    pub confirm_quit: bool,
+    /// 시작 시 타이틀 줄에 현재 곡 표시
+    pub title_now_playing: bool,
+    /// 일시정지 중 화면 흐리게 표시
+    pub dim_when_paused: bool,
+    /// 트랙 정보 아래에 마우스로 누를 수 있는 재생 버튼(⏮ ⏯ ⏭) 표시
+    pub show_buttons: bool,
+    /// 아트워크 테두리 스타일
+    pub artwork_border: ArtworkBorder,
+    /// 아트워크가 없을 때 표시할 대체 이미지
+    pub artwork_fallback: ArtworkFallback,
+    /// 검색 결과에서 (이름, 아티스트, 앨범)이 같은 중복 트랙 합치기
+    pub dedupe_results: bool,
+    /// 보관함 검색 결과를 퍼지 매칭 점수순으로 정렬 (오타가 있어도 단어별로 찾아 순위 매김)
+    pub fuzzy_search: bool,
+    /// 재생 대기열이 끝나면 비슷한 곡(같은 아티스트/장르)을 이어서 재생 (`I` 키로 전환 가능)
+    pub autoplay_similar: bool,
+    /// 재생한 곡을 `history.jsonl`에 누적 기록 (`--export-history`로 내보내기)
+    pub record_history: bool,
+    /// 누적 재생 기록 최대 건수 (넘으면 오래된 기록부터 삭제)
+    pub history_max_entries: usize,
+    /// 포커스 모드에서 아트워크 후보를 번갈아 보여주는 슬라이드쇼 (`w` 키로 전환 가능)
+    pub artwork_slideshow: bool,
+    /// 슬라이드쇼 전환 간격 (초)
+    pub slideshow_interval: u64,
+    /// 로컬 파일 트랙의 음량 파형을 진행 바 배경으로 표시 (`ffmpeg` 필요)
+    pub progress_waveform: bool,
+    /// 마우스로 목록 클릭/스크롤 (끄면 터미널 기본 텍스트 선택 사용)
+    pub mouse: bool,
+    /// Discord 활동에 현재 곡 표시 (`discord_client_id` 필요)
+    pub discord_presence: bool,
+    /// Discord 개발자 포털에서 만든 애플리케이션 ID
+    pub discord_client_id: Option<String>,
+    /// 명령을 읽을 FIFO 경로 (예: "~/.config/apple-music-tui/control", 없으면 비활성화)
+    pub command_fifo: Option<String>,
+    /// 내장 테마 이름: "default" | "mono"
+    pub theme: String,
+    /// 테마 위에 덮어쓸 색상 (`[colors]`)
+    pub colors: ThemeColors,
+    /// 키맵 프리셋 이름: "default" | "vim" | "emacs" | "arrows-only"
+    pub keymap_preset: String,
+    /// 동작 이름 → 키 (프리셋 위에 추가, 예: `next = "n"`, `volume_up = ["+", "ctrl-up"]`)
+    pub keys: HashMap<String, KeyBinding>,
+    /// 시작 시 적용할 프로필 이름 (`--profile`로 덮어쓰기 가능)
+    pub profile: Option<String>,
+    /// 이름 붙은 프로필 목록 (`[profiles.desk]` 등)
+    pub profiles: HashMap<String, Profile>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            search_auto_advance: false,
+            skip_marks_disliked: false,
+            start_action: StartAction::default(),
+            start_playlist: None,
+            skip_starts_playback: false,
+            volume_target: VolumeTarget::default(),
+            volume_step: 5,
+            http_timeout_secs: 5,
+            persistent_osascript: false,
+            poll_interval_ms: 1000,
+            output_device: None,
+            volume_wrap: false,
+            duck_volume: 10,
+            confirm_quit: false,
+            title_now_playing: false,
+            dim_when_paused: false,
+            show_buttons: false,
+            artwork_border: ArtworkBorder::default(),
+            artwork_fallback: ArtworkFallback::default(),
+            dedupe_results: false,
+            fuzzy_search: false,
+            autoplay_similar: false,
+            record_history: false,
+            history_max_entries: 10_000,
+            artwork_slideshow: false,
+            slideshow_interval: 15,
+            progress_waveform: false,
+            mouse: true,
+            discord_presence: false,
+            discord_client_id: None,
+            command_fifo: None,
+            theme: "default".to_string(),
+            colors: ThemeColors::default(),
+            keymap_preset: "default".to_string(),
+            keys: HashMap::new(),
+            profile: None,
+            profiles: HashMap::new(),
+        }
+    }
+}
+
+impl Config {
+    /// 설정 디렉토리 경로 (`~/.config/apple-music-tui`)
+    pub fn dir() -> Option<PathBuf> {
+        std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config").join("apple-music-tui"))
+    }
+
+    /// 설정 파일 경로
+    pub fn path() -> Option<PathBuf> {
+        Self::dir().map(|dir| dir.join("config.toml"))
+    }
+
+    /// 프로필을 적용한 설정 (없는 프로필이면 None)
+    pub fn with_profile(&self, name: &str) -> Option<Config> {
+        let profile = self.profiles.get(name)?;
+        let mut config = self.clone();
+        if let Some(step) = profile.volume_step {
+            config.volume_step = step;
+        }
+        if let Some(interval) = profile.poll_interval_ms {
+            config.poll_interval_ms = interval;
+        }
+        if let Some(target) = profile.volume_target {
+            config.volume_target = target;
+        }
+        if let Some(device) = &profile.output_device {
+            config.output_device = Some(device.clone());
+        }
+        if let Some(border) = profile.artwork_border {
+            config.artwork_border = border;
+        }
+        if let Some(dim) = profile.dim_when_paused {
+            config.dim_when_paused = dim;
+        }
+        if let Some(theme) = &profile.theme {
+            config.theme = theme.clone();
+        }
+        Some(config)
+    }
+
+    /// 프로필 이름 목록 (이름순)
+    pub fn profile_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+        names.sort();
+        names
+    }
+
+    /// 설정 파일 로드 (파일이 없으면 기본값)
+    pub fn load() -> Result<Self> {
+        let Some(path) = Self::path() else {
+            return Ok(Self::default());
+        };
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(&path).context("설정 파일 읽기 실패")?;
+        toml::from_str(&content).context("설정 파일 파싱 실패")
+    }
+}
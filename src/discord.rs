@@ -0,0 +1,115 @@
+//! Discord Rich Presence 모듈
+//! 곡이나 재생 상태가 바뀔 때 Discord 활동을 "Listening to"로 갱신합니다.
+//! IPC 통신은 별도 스레드에서 처리하므로 Discord가 응답하지 않아도 UI 루프는 멈추지 않고,
+//! Discord가 실행 중이 아니면 조용히 건너뛴 뒤 다음 갱신 때 다시 연결을 시도합니다.
+
+use crate::jxa::{PlayerState, TrackInfo};
+use discord_rich_presence::activity::{Activity, ActivityType, Timestamps};
+use discord_rich_presence::{DiscordIpc, DiscordIpcClient};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// 종료 시 활동을 지우는 작업 스레드를 기다리는 최대 시간 (Discord가 응답하지 않아도 종료가 멈추지 않도록)
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// 작업 스레드로 보내는 요청
+enum Message {
+    /// 재생 중인 곡 표시 (`start`는 곡 시작 시각, 유닉스 초)
+    Set { title: String, artist: String, start: i64 },
+    /// 활동 지우기
+    Clear,
+    /// 활동을 지우고 연결 종료
+    Shutdown,
+}
+
+/// Discord 활동 갱신기
+pub struct Presence {
+    tx: Sender<Message>,
+    /// 작업 스레드가 끝나면 닫히는 채널
+    done: Receiver<()>,
+    /// 마지막으로 보낸 (트랙 ID, 재생 상태) - 같은 상태면 다시 보내지 않음
+    last: Option<(String, PlayerState)>,
+}
+
+impl Presence {
+    /// 작업 스레드 시작 (연결은 첫 갱신 때 시도)
+    pub fn spawn(client_id: &str) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let (done_tx, done) = mpsc::channel();
+        let client_id = client_id.to_string();
+        std::thread::spawn(move || {
+            let mut client = DiscordIpcClient::new(&client_id);
+            let mut connected = false;
+            for message in rx {
+                let shutdown = matches!(message, Message::Shutdown);
+                if !connected && !shutdown {
+                    connected = client.connect().is_ok();
+                }
+                if !connected {
+                    if shutdown {
+                        break;
+                    }
+                    continue;
+                }
+
+                let result = match message {
+                    Message::Set { title, artist, start } => client.set_activity(
+                        Activity::new()
+                            .activity_type(ActivityType::Listening)
+                            .details(title)
+                            .state(format!("by {}", artist))
+                            .timestamps(Timestamps::new().start(start)),
+                    ),
+                    Message::Clear | Message::Shutdown => client.clear_activity(),
+                };
+                // 연결이 끊겼으면 다음 갱신 때 다시 연결
+                if result.is_err() || shutdown {
+                    let _ = client.close();
+                    connected = false;
+                }
+                if shutdown {
+                    break;
+                }
+            }
+            let _ = done_tx.send(());
+        });
+
+        Presence { tx, done, last: None }
+    }
+
+    /// 현재 트랙으로 활동 갱신 (곡이나 재생 상태가 바뀐 경우에만 전송)
+    /// 일시정지/정지 중에는 경과 시간이 맞지 않으므로 활동을 지웁니다.
+    pub fn update(&mut self, track: &TrackInfo) {
+        let key = (track.id.clone(), track.state.clone());
+        if self.last.as_ref() == Some(&key) {
+            return;
+        }
+        self.last = Some(key);
+
+        let message = if track.state == PlayerState::Playing && !track.name.is_empty() {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64);
+            Message::Set {
+                title: track.name.clone(),
+                artist: track.artist.clone(),
+                start: now - track.player_position as i64,
+            }
+        } else {
+            Message::Clear
+        };
+        let _ = self.tx.send(message);
+    }
+
+    /// 재생 위치가 바뀌었을 때 경과 시간이 맞도록 상태가 같아도 다시 전송
+    pub fn resync(&mut self, track: &TrackInfo) {
+        self.last = None;
+        self.update(track);
+    }
+}
+
+impl Drop for Presence {
+    /// 종료 시 활동을 지우고 작업 스레드가 끝날 때까지 대기 (`SHUTDOWN_TIMEOUT`이 지나면 기다리지 않음)
+    fn drop(&mut self) {
+        let _ = self.tx.send(Message::Shutdown);
+        let _ = self.done.recv_timeout(SHUTDOWN_TIMEOUT);
+    }
+}
@@ -1,48 +1,118 @@
 //! 이벤트 핸들링 모듈
 
-use crate::app::{App, AppMode};
-use crossterm::event::{KeyCode, KeyEvent};
+use crate::app::{Action, App, AppMode};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// 가사 보기/도움말에서 PageUp/PageDown 한 번에 스크롤할 줄 수
+const LYRICS_PAGE: u16 = 10;
 
 /// 키보드 이벤트 처리
 pub fn handle_key_event(app: &mut App, key: KeyEvent) {
+    // 새로고침은 모든 모드에서 동작
+    if app.keymap.get(key) == Some(Action::Refresh) {
+        app.perform(Action::Refresh);
+        return;
+    }
+
+    // 도움말 오버레이는 어느 모드 위에서든 키 입력을 먼저 받음
+    if app.show_help {
+        handle_help_overlay(app, key);
+        return;
+    }
+
+    // 팝업에서도 `?`로 도움말 열기 (기본 모드는 키맵, 텍스트 입력 중에는 글자로 입력)
+    if key.code == KeyCode::Char('?')
+        && !matches!(app.mode, AppMode::Normal | AppMode::SearchInput | AppMode::SearchFilter | AppMode::Command)
+    {
+        app.toggle_help();
+        return;
+    }
+
     match app.mode {
         AppMode::Normal => handle_normal_mode(app, key),
         AppMode::SearchInput => handle_search_input_mode(app, key),
         AppMode::SearchResults => handle_search_results_mode(app, key),
+        AppMode::SearchFilter => handle_search_filter_mode(app, key),
+        AppMode::SkipLog => handle_skip_log_mode(app, key),
+        AppMode::AirPlay => handle_airplay_mode(app, key),
+        AppMode::UpNext => handle_up_next_mode(app, key),
+        AppMode::Playlists => handle_playlists_mode(app, key),
+        AppMode::Equalizer => handle_equalizer_mode(app, key),
+        AppMode::SessionStats => handle_session_stats_mode(app, key),
+        AppMode::LibraryStats => handle_library_stats_mode(app, key),
+        AppMode::Lyrics => handle_lyrics_mode(app, key),
+        AppMode::Browser => handle_browser_mode(app, key),
+        AppMode::Command => handle_command_mode(app, key),
+        AppMode::Confirm { .. } => handle_confirm_mode(app, key),
     }
 }
 
-/// 기본 모드 키 핸들링
-fn handle_normal_mode(app: &mut App, key: KeyEvent) {
-    match key.code {
-        // 재생/일시정지
-        KeyCode::Char(' ') => app.toggle_play_pause(),
-        
-        // 이전 곡
-        KeyCode::Left | KeyCode::Char('h') => app.previous_track(),
-        
-        // 다음 곡
-        KeyCode::Right | KeyCode::Char('l') => app.next_track(),
-        
-        // 볼륨 증가
-        KeyCode::Up | KeyCode::Char('k') => app.volume_up(),
-        
-        // 볼륨 감소
-        KeyCode::Down | KeyCode::Char('j') => app.volume_down(),
-        
-        // 검색 모드 진입
-        KeyCode::Char('/') => {
-            app.mode = AppMode::SearchInput;
-            app.search_query.clear();
+/// 마우스 이벤트 처리 (재생 버튼/팝업 목록 클릭, 휠)
+pub fn handle_mouse_event(app: &mut App, mouse: MouseEvent) {
+    // 도움말이 열려 있으면 휠로 스크롤만
+    if app.show_help {
+        match mouse.kind {
+            MouseEventKind::ScrollUp => app.help_scroll_up(1),
+            MouseEventKind::ScrollDown => app.help_scroll_down(1),
+            _ => {}
         }
+        return;
+    }
 
-        // 종료
-        KeyCode::Char('q') | KeyCode::Esc => app.quit(),
-        
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) if app.mode == AppMode::Normal => {
+            // 바와 버튼은 겹치지 않으므로 둘 다 판정
+            app.click_gauge(mouse.column, mouse.row);
+            app.click_button(mouse.column, mouse.row);
+        }
+        MouseEventKind::Down(MouseButton::Left) => app.click_list(mouse.column, mouse.row),
+        MouseEventKind::ScrollUp => app.list_select_prev(),
+        MouseEventKind::ScrollDown => app.list_select_next(),
         _ => {}
     }
 }
 
+/// 도움말 오버레이 키 핸들링
+fn handle_help_overlay(app: &mut App, key: KeyEvent) {
+    match key.code {
+        // 닫기
+        KeyCode::Esc | KeyCode::Char('?') => app.toggle_help(),
+
+        // 스크롤
+        KeyCode::Up | KeyCode::Char('k') => app.help_scroll_up(1),
+        KeyCode::Down | KeyCode::Char('j') => app.help_scroll_down(1),
+        KeyCode::PageUp => app.help_scroll_up(LYRICS_PAGE),
+        KeyCode::PageDown => app.help_scroll_down(LYRICS_PAGE),
+
+        _ => {}
+    }
+}
+
+/// 입력 끝의 글자 하나 지우기 (결합 문자/이모지 시퀀스도 한 글자로 취급)
+fn pop_grapheme(input: &mut String) {
+    if let Some((index, _)) = input.grapheme_indices(true).next_back() {
+        input.truncate(index);
+    }
+}
+
+/// 기본 모드 키 핸들링 (키맵에 매핑된 동작 실행)
+fn handle_normal_mode(app: &mut App, key: KeyEvent) {
+    // `g` 다음 숫자: 트랙의 해당 퍼센트 위치로 이동 (다른 키면 취소)
+    if std::mem::take(&mut app.seek_prefix) {
+        if let KeyCode::Char(c) = key.code
+            && let Some(digit) = c.to_digit(10)
+        {
+            app.seek_percent(digit as u8 * 10);
+        }
+        return;
+    }
+
+    if let Some(action) = app.keymap.get(key) {
+        app.perform(action);
+    }
+}
+
 /// 검색 입력 모드 키 핸들링
 fn handle_search_input_mode(app: &mut App, key: KeyEvent) {
     match key.code {
@@ -50,24 +120,27 @@ fn handle_search_input_mode(app: &mut App, key: KeyEvent) {
         KeyCode::Enter => app.perform_search(),
         
         // 취소
-        KeyCode::Esc => {
-            app.mode = AppMode::Normal;
-            app.search_query.clear();
-        }
+        KeyCode::Esc => app.close_search(),
         
         // 백스페이스
         KeyCode::Backspace => {
-            app.search_query.pop();
+            pop_grapheme(&mut app.search_query);
+            app.search_input_changed();
         }
         
+        // 보관함 검색 범위: 플레이리스트 고르기 / 보관함 전체로 되돌리기 (Ctrl-P)
+        KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => app.toggle_search_scope(),
+
         // 문자 입력
         KeyCode::Char(c) => {
             app.search_query.push(c);
+            app.search_input_changed();
         }
         
         // 검색 모드 전환 (Tab)
         KeyCode::Tab => {
             app.toggle_search_mode();
+            app.search_input_changed();
         }
 
         _ => {}
@@ -76,23 +149,314 @@ fn handle_search_input_mode(app: &mut App, key: KeyEvent) {
 
 /// 검색 결과 선택 모드 키 핸들링
 fn handle_search_results_mode(app: &mut App, key: KeyEvent) {
+    // vim식 숫자 접두사(`5j`)와 `gg` (다른 키를 누르면 대기 상태 초기화)
+    let digit = match key.code {
+        KeyCode::Char('0') if !app.has_pending_count() => None,
+        KeyCode::Char(c) => c.to_digit(10),
+        _ => None,
+    };
+    if let Some(digit) = digit {
+        app.push_count_digit(digit);
+        return;
+    }
+    if key.code == KeyCode::Char('g') {
+        app.search_press_g();
+        return;
+    }
+    let count = app.take_pending_count();
+
     match key.code {
         // 선택 및 재생
         KeyCode::Enter => app.search_play_selection(),
+
+        // 선택한 결과부터 나머지 결과 이어서 재생
+        KeyCode::Char('a') => app.search_play_from_selection(),
+
+        // 선택한 곡이 속한 앨범 전체 재생
+        KeyCode::Char('A') => app.search_play_album(),
+
+        // 현재 곡 다음에 재생되도록 추가 (지금 곡은 계속 재생)
+        KeyCode::Char('n') => app.search_queue_next(),
+
+        // 재생 시간/BPM 필터
+        KeyCode::Char('f') => app.open_search_filter(),
+
+        // 검색어 수정 (Enter로 다시 검색)
+        KeyCode::Char('/') => app.set_mode(AppMode::SearchInput),
         
         // 취소
-        KeyCode::Esc => {
-            app.mode = AppMode::Normal;
-            app.search_query.clear();
-            app.search_results.clear();
-        }
+        KeyCode::Esc => app.close_search(),
         
+        // 위로 이동 (숫자 접두사만큼 반복)
+        KeyCode::Up | KeyCode::Char('k') => {
+            for _ in 0..count.unwrap_or(1) {
+                app.search_select_prev();
+            }
+        }
+
+        // 아래로 이동 (숫자 접두사만큼 반복)
+        KeyCode::Down | KeyCode::Char('j') => {
+            for _ in 0..count.unwrap_or(1) {
+                app.search_select_next();
+            }
+        }
+
+        // 마지막 결과로 이동 (숫자 접두사가 있으면 그 번호의 결과)
+        KeyCode::Char('G') => match count {
+            Some(count) => app.search_select_index(count.saturating_sub(1)),
+            None => app.search_select_last(),
+        },
+
+        // 한 페이지 위/아래로 이동
+        KeyCode::PageUp => app.search_select_page_up(),
+        KeyCode::PageDown => app.search_select_page_down(),
+        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => app.search_select_page_up(),
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => app.search_select_page_down(),
+
+        // 처음/마지막 결과로 이동
+        KeyCode::Home => app.search_select_first(),
+        KeyCode::End => app.search_select_last(),
+
+        _ => {}
+    }
+}
+
+/// 검색 결과 필터 입력 모드 키 핸들링
+fn handle_search_filter_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        // 필터 적용
+        KeyCode::Enter => app.apply_search_filter(),
+
+        // 취소 (결과 목록으로 복귀)
+        KeyCode::Esc => app.mode = AppMode::SearchResults,
+
+        // 백스페이스
+        KeyCode::Backspace => {
+            pop_grapheme(&mut app.search_filter_input);
+        }
+
+        // 문자 입력
+        KeyCode::Char(c) => app.search_filter_input.push(c),
+
+        _ => {}
+    }
+}
+
+/// 명령 팔레트 입력 모드 키 핸들링
+fn handle_command_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        // 명령 실행
+        KeyCode::Enter => app.execute_command_input(),
+
+        // 취소
+        KeyCode::Esc => app.mode = AppMode::Normal,
+
+        // 백스페이스
+        KeyCode::Backspace => {
+            pop_grapheme(&mut app.command_input);
+        }
+
+        // 문자 입력
+        KeyCode::Char(c) => app.command_input.push(c),
+
+        _ => {}
+    }
+}
+
+/// 스킵 기록 보기 모드 키 핸들링
+fn handle_skip_log_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        // 닫기
+        KeyCode::Esc | KeyCode::Char('X') => app.mode = AppMode::Normal,
+
         // 위로 이동
-        KeyCode::Up | KeyCode::Char('k') => app.search_select_prev(),
-        
+        KeyCode::Up | KeyCode::Char('k') => app.skip_log_select_prev(),
+
         // 아래로 이동
-        KeyCode::Down | KeyCode::Char('j') => app.search_select_next(),
-        
+        KeyCode::Down | KeyCode::Char('j') => app.skip_log_select_next(),
+
+        // 기록 전체 삭제 (확인 후)
+        KeyCode::Char('D') => app.confirm("Clear the whole skip log?", Action::ClearSkipLog),
+
         _ => {}
     }
 }
+
+/// AirPlay 장치 목록 모드 키 핸들링
+fn handle_airplay_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        // 닫기
+        KeyCode::Esc | KeyCode::Char('o') => app.mode = AppMode::Normal,
+
+        // 위로 이동
+        KeyCode::Up | KeyCode::Char('k') => app.airplay_select_prev(),
+
+        // 아래로 이동
+        KeyCode::Down | KeyCode::Char('j') => app.airplay_select_next(),
+
+        // 장치 볼륨 조절
+        KeyCode::Left | KeyCode::Char('h') => app.airplay_adjust_volume(-5),
+        KeyCode::Right | KeyCode::Char('l') => app.airplay_adjust_volume(5),
+
+        // 출력 그룹에 추가/제거
+        KeyCode::Enter | KeyCode::Char(' ') => app.airplay_toggle_selected(),
+
+        _ => {}
+    }
+}
+
+/// Up Next 팝업 키 핸들링
+fn handle_up_next_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        // 닫기
+        KeyCode::Esc | KeyCode::Char('u') => app.mode = AppMode::Normal,
+
+        // 위로 이동
+        KeyCode::Up | KeyCode::Char('k') => app.up_next_select_prev(),
+
+        // 아래로 이동
+        KeyCode::Down | KeyCode::Char('j') => app.up_next_select_next(),
+
+        _ => {}
+    }
+}
+
+/// 플레이리스트 목록 키 핸들링
+fn handle_playlists_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        // 닫기
+        KeyCode::Esc | KeyCode::Char('p') => app.close_playlists(),
+
+        // 위로 이동
+        KeyCode::Up | KeyCode::Char('k') => app.playlists_select_prev(),
+
+        // 아래로 이동
+        KeyCode::Down | KeyCode::Char('j') => app.playlists_select_next(),
+
+        // 재생
+        KeyCode::Enter => app.playlists_play_selection(),
+
+        _ => {}
+    }
+}
+
+/// 이퀄라이저 프리셋 목록 키 핸들링
+fn handle_equalizer_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        // 닫기
+        KeyCode::Esc | KeyCode::Char('e') => app.mode = AppMode::Normal,
+
+        // 위로 이동
+        KeyCode::Up | KeyCode::Char('k') => app.eq_select_prev(),
+
+        // 아래로 이동
+        KeyCode::Down | KeyCode::Char('j') => app.eq_select_next(),
+
+        // 프리셋 적용
+        KeyCode::Enter => app.eq_apply_selection(),
+
+        // 이퀄라이저 켜기/끄기
+        KeyCode::Char(' ') => app.toggle_eq_enabled(),
+
+        _ => {}
+    }
+}
+
+/// 세션 통계 팝업 키 핸들링
+fn handle_session_stats_mode(app: &mut App, key: KeyEvent) {
+    if matches!(key.code, KeyCode::Esc | KeyCode::Char('S')) {
+        app.mode = AppMode::Normal;
+    }
+}
+
+/// 보관함 통계 보기 키 핸들링
+fn handle_library_stats_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        // 닫기
+        KeyCode::Esc | KeyCode::Char('i') => app.mode = AppMode::Normal,
+
+        // 다시 세기
+        KeyCode::Char('r') => app.refresh_library_stats(),
+
+        _ => {}
+    }
+}
+
+/// 가사 보기 키 핸들링
+fn handle_lyrics_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        // 닫기
+        KeyCode::Esc | KeyCode::Char('L') => app.mode = AppMode::Normal,
+
+        // 스크롤 (타임스탬프 없는 가사)
+        KeyCode::Up | KeyCode::Char('k') => app.lyrics_scroll_up(1),
+        KeyCode::Down | KeyCode::Char('j') => app.lyrics_scroll_down(1),
+        KeyCode::PageUp => app.lyrics_scroll_up(LYRICS_PAGE),
+        KeyCode::PageDown => app.lyrics_scroll_down(LYRICS_PAGE),
+
+        _ => {}
+    }
+}
+
+/// 라이브러리 브라우저 키 핸들링
+fn handle_browser_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        // 이동
+        KeyCode::Up | KeyCode::Char('k') => app.browser.select_prev(),
+        KeyCode::Down | KeyCode::Char('j') => app.browser.select_next(),
+
+        // 들어가기 / 재생
+        KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') => app.browser_enter(),
+
+        // 상위 단계 (최상위에서는 닫기)
+        KeyCode::Left | KeyCode::Backspace | KeyCode::Char('h') => app.browser_back(),
+
+        // 재생/일시정지
+        KeyCode::Char(' ') => app.perform(Action::PlayPause),
+
+        // Now Playing 화면으로
+        KeyCode::Esc | KeyCode::Char('B') => app.mode = AppMode::Normal,
+
+        KeyCode::Char('q') => app.request_quit(),
+
+        _ => {}
+    }
+}
+
+/// 확인 팝업 키 핸들링
+fn handle_confirm_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('y') | KeyCode::Char('Y') => app.resolve_confirm(true),
+        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => app.resolve_confirm(false),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pop_grapheme_removes_whole_emoji_sequence() {
+        // ZWJ 가족 이모지와 피부색 수식 이모지는 코드 포인트 여러 개지만 한 번에 지워져야 함
+        let mut input = "hi 👨‍👩‍👧".to_string();
+        pop_grapheme(&mut input);
+        assert_eq!(input, "hi ");
+
+        let mut input = "ok👍🏽".to_string();
+        pop_grapheme(&mut input);
+        assert_eq!(input, "ok");
+
+        // 결합 문자(e + U+0301)도 한 글자로 취급
+        let mut input = "cafe\u{301}".to_string();
+        pop_grapheme(&mut input);
+        assert_eq!(input, "caf");
+    }
+
+    #[test]
+    fn pop_grapheme_on_empty_input_does_nothing() {
+        let mut input = String::new();
+        pop_grapheme(&mut input);
+        assert!(input.is_empty());
+    }
+}
@@ -0,0 +1,55 @@
+//! FIFO 명령 인터페이스 모듈
+//! 설정한 named pipe에서 한 줄씩 명령(`play`, `next`, `volume 70` 등, `command` 모듈 참고)을 읽어
+//! TUI가 실행 중일 때 외부 스크립트/단축키로 재생을 제어할 수 있게 합니다.
+
+use crate::command::Command;
+use anyhow::{bail, Context, Result};
+use std::io::{BufRead, BufReader};
+use std::os::unix::fs::FileTypeExt;
+use std::path::{Path, PathBuf};
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+/// 설정 값의 `~/`를 홈 디렉토리로 확장
+pub fn expand_path(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// FIFO가 없으면 만들고, 명령을 읽는 스레드를 시작
+/// 읽기 스레드는 open()에서 쓰는 쪽을 기다리며 블로킹되므로 tokio 블로킹 풀 대신
+/// 별도 스레드를 사용합니다 (종료 시 런타임이 기다리지 않도록).
+pub fn spawn_reader(path: &Path) -> Result<UnboundedReceiver<Command>> {
+    if !path.exists() {
+        let status = std::process::Command::new("mkfifo")
+            .arg(path)
+            .status()
+            .context("mkfifo 실행 실패")?;
+        if !status.success() {
+            bail!("FIFO 생성 실패: {}", path.display());
+        }
+    }
+    // 일반 파일이면 open()이 바로 EOF를 돌려줘 읽기 루프가 쉬지 않고 돌게 됨
+    let metadata = std::fs::metadata(path).context("FIFO 정보 읽기 실패")?;
+    if !metadata.file_type().is_fifo() {
+        bail!("FIFO가 아닌 파일: {}", path.display());
+    }
+
+    let (tx, rx) = mpsc::unbounded_channel();
+    let path = path.to_path_buf();
+    std::thread::spawn(move || {
+        // 쓰는 쪽이 닫히면 EOF가 오므로 다시 열어서 계속 대기
+        while let Ok(file) = std::fs::File::open(&path) {
+            for line in BufReader::new(file).lines().map_while(|line| line.ok()) {
+                if let Some(command) = Command::parse(&line)
+                    && tx.send(command).is_err()
+                {
+                    return;
+                }
+            }
+        }
+    });
+
+    Ok(rx)
+}
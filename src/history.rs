@@ -0,0 +1,175 @@
+//! 재생 기록 모듈
+//! 재생한 곡을 `history.jsonl`에 한 줄씩 누적 기록하고,
+//! 세션/누적 기록을 Markdown 또는 CSV로 내보냅니다.
+
+use crate::config::Config;
+use crate::jxa::TrackInfo;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 재생 기록 한 건
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// 재생 시작 시각 (유닉스 초)
+    pub played_at: u64,
+    pub name: String,
+    pub artist: String,
+    pub album: String,
+}
+
+impl HistoryEntry {
+    /// 지금 재생을 시작한 트랙으로 기록 생성
+    pub fn now(track: &TrackInfo) -> Self {
+        let played_at = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        HistoryEntry {
+            played_at,
+            name: track.name.clone(),
+            artist: track.artist.clone(),
+            album: track.album.clone(),
+        }
+    }
+}
+
+/// 내보내기 형식
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Markdown,
+    Csv,
+}
+
+impl ExportFormat {
+    /// 파일 확장자로 형식 결정 (`.csv`가 아니면 Markdown)
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => ExportFormat::Csv,
+            _ => ExportFormat::Markdown,
+        }
+    }
+
+    /// 파일 확장자
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Csv => "csv",
+        }
+    }
+}
+
+/// 누적 재생 기록 파일 경로
+fn path() -> Option<PathBuf> {
+    Config::dir().map(|dir| dir.join("history.jsonl"))
+}
+
+/// 누적 재생 기록에 한 건 추가 (`max_entries`를 넘으면 오래된 기록부터 삭제)
+pub fn append(entry: &HistoryEntry, max_entries: usize) -> Result<()> {
+    let path = path().context("HOME 경로를 찾을 수 없음")?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).context("설정 디렉토리 생성 실패")?;
+    }
+    let line = serde_json::to_string(entry).context("재생 기록 직렬화 실패")?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .context("재생 기록 파일 열기 실패")?;
+    writeln!(file, "{}", line).context("재생 기록 저장 실패")?;
+    trim(&path, max_entries)
+}
+
+/// 기록 파일을 최근 `max_entries`줄만 남기고 정리 (넘지 않으면 그대로)
+fn trim(path: &Path, max_entries: usize) -> Result<()> {
+    let content = std::fs::read_to_string(path).context("재생 기록 파일 읽기 실패")?;
+    let lines: Vec<&str> = content.lines().collect();
+    if lines.len() <= max_entries {
+        return Ok(());
+    }
+    let mut kept = lines[lines.len() - max_entries..].join("\n");
+    if !kept.is_empty() {
+        kept.push('\n');
+    }
+    std::fs::write(path, kept).context("재생 기록 정리 실패")
+}
+
+/// 누적 재생 기록 로드 (읽을 수 없는 줄은 건너뜀)
+pub fn load() -> Vec<HistoryEntry> {
+    path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .map(|content| content.lines().filter_map(|line| serde_json::from_str(line).ok()).collect())
+        .unwrap_or_default()
+}
+
+/// 재생 기록을 파일로 내보내기
+pub fn export(entries: &[HistoryEntry], path: &Path, format: ExportFormat) -> Result<()> {
+    let content = match format {
+        ExportFormat::Markdown => to_markdown(entries),
+        ExportFormat::Csv => to_csv(entries),
+    };
+    if let Some(dir) = path.parent()
+        && !dir.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(dir).context("내보내기 디렉토리 생성 실패")?;
+    }
+    std::fs::write(path, content).context("재생 기록 내보내기 실패")
+}
+
+/// Markdown 표 형식
+fn to_markdown(entries: &[HistoryEntry]) -> String {
+    let cell = |s: &str| s.replace('|', "\\|");
+    let mut out = String::from("# Listening History\n\n| Played at (UTC) | Title | Artist | Album |\n|---|---|---|---|\n");
+    for entry in entries {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            format_timestamp(entry.played_at),
+            cell(&entry.name),
+            cell(&entry.artist),
+            cell(&entry.album)
+        ));
+    }
+    out
+}
+
+/// CSV 형식 (RFC 4180 따옴표 처리)
+fn to_csv(entries: &[HistoryEntry]) -> String {
+    let field = |s: &str| format!("\"{}\"", s.replace('"', "\"\""));
+    let mut out = String::from("played_at,title,artist,album\n");
+    for entry in entries {
+        out.push_str(&format!(
+            "{},{},{},{}\n",
+            format_timestamp(entry.played_at),
+            field(&entry.name),
+            field(&entry.artist),
+            field(&entry.album)
+        ));
+    }
+    out
+}
+
+/// 유닉스 초를 `YYYY-MM-DD HH:MM:SS` (UTC)로 변환
+pub fn format_timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+
+    // 1970-01-01부터의 일수를 그레고리력 날짜로 변환 (Howard Hinnant의 civil_from_days)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        rem / 3_600,
+        rem % 3_600 / 60,
+        rem % 60
+    )
+}
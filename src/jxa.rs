@@ -1,11 +1,23 @@
 //! JXA (JavaScript for Automation) 통신 모듈
 //! macOS Music.app을 osascript를 통해 제어합니다.
 
-use anyhow::{Context, Result};
+use crate::config::{Config, StartAction};
+use anyhow::{bail, Context, Result};
 use serde::Deserialize;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::io::Write;
-use std::path::PathBuf;
-use std::process::Command;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+#[cfg(target_os = "macos")]
+use std::io::{BufRead, BufReader, Read};
+#[cfg(target_os = "macos")]
+use std::process::{Command, Stdio};
+#[cfg(target_os = "macos")]
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+#[cfg(target_os = "macos")]
+use wait_timeout::ChildExt;
 
 /// 플레이어 상태
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -26,50 +38,411 @@ impl From<&str> for PlayerState {
     }
 }
 
+/// 반복 재생 모드
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RepeatMode {
+    #[default]
+    Off,
+    One,
+    All,
+}
+
+impl From<&str> for RepeatMode {
+    fn from(s: &str) -> Self {
+        match s {
+            "one" => RepeatMode::One,
+            "all" => RepeatMode::All,
+            _ => RepeatMode::Off,
+        }
+    }
+}
+
+impl RepeatMode {
+    /// Music.app `songRepeat` 값
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RepeatMode::Off => "off",
+            RepeatMode::One => "one",
+            RepeatMode::All => "all",
+        }
+    }
+
+    /// 다음 모드 (off → all → one → off)
+    pub fn next(self) -> Self {
+        match self {
+            RepeatMode::Off => RepeatMode::All,
+            RepeatMode::All => RepeatMode::One,
+            RepeatMode::One => RepeatMode::Off,
+        }
+    }
+}
+
 /// 현재 재생 중인 트랙 정보
 #[derive(Debug, Clone, Default)]
 pub struct TrackInfo {
+    /// 트랙 persistentID (stopped 상태면 빈 문자열)
+    pub id: String,
     pub name: String,
     pub artist: String,
     pub album: String,
+    /// 발매 연도 (알 수 없으면 0)
+    pub year: u32,
+    /// 장르 (스트리밍 곡 등 메타데이터가 없으면 빈 문자열)
+    pub genre: String,
     pub duration: f64,
     pub player_position: f64,
     pub state: PlayerState,
+    /// 파일 종류 (예: "Apple Lossless audio file")
+    pub kind: String,
+    /// 샘플레이트 (Hz, 알 수 없으면 0)
+    pub sample_rate: u32,
+    /// 비트레이트 (kbps, 알 수 없으면 0)
+    pub bit_rate: u32,
+    /// 작곡가 (클래식 등, 없으면 빈 문자열)
+    pub composer: String,
+    /// 앨범 아티스트 (클래식 앨범은 지휘자/오케스트라인 경우가 많음)
+    pub album_artist: String,
+    /// 별점 (0-5)
+    pub rating: u8,
+    /// 좋아요 표시 여부
+    pub loved: bool,
+    /// 싫어요 표시 여부
+    pub disliked: bool,
+}
+
+/// 무손실 재생 품질
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AudioQuality {
+    Lossless,
+    HiResLossless,
+}
+
+impl TrackInfo {
+    /// 파일 종류에서 오디오 포맷 이름 (예: "Apple Music AAC audio file" → "AAC", 알 수 없으면 빈 문자열)
+    pub fn format_name(&self) -> String {
+        let kind = self.kind.to_lowercase();
+        let known = [("lossless", "ALAC"), ("aac", "AAC"), ("mpeg", "MP3"), ("aiff", "AIFF"), ("wav", "WAV"), ("flac", "FLAC")];
+        match known.iter().find(|(pattern, _)| kind.contains(pattern)) {
+            Some((_, name)) => name.to_string(),
+            None => self.kind.trim_end_matches(" audio file").to_string(),
+        }
+    }
+
+    /// 파일 종류와 샘플레이트로 무손실/Hi-Res 여부 판단 (알 수 없으면 None)
+    pub fn quality(&self) -> Option<AudioQuality> {
+        let kind = self.kind.to_lowercase();
+        let lossless = ["lossless", "aiff", "wav", "flac"].iter().any(|k| kind.contains(k));
+        if !lossless {
+            return None;
+        }
+        if self.sample_rate > 48_000 {
+            Some(AudioQuality::HiResLossless)
+        } else {
+            Some(AudioQuality::Lossless)
+        }
+    }
 }
 
 /// JXA 스크립트 실행 결과를 파싱하기 위한 구조체
 #[derive(Deserialize)]
 struct RawTrackInfo {
+    #[serde(default)]
+    id: String,
     name: String,
     artist: String,
     album: String,
+    #[serde(default)]
+    year: u32,
+    #[serde(default)]
+    genre: String,
     duration: f64,
     #[serde(rename = "playerPosition")]
     player_position: f64,
     state: String,
+    #[serde(default)]
+    kind: String,
+    #[serde(default, rename = "sampleRate")]
+    sample_rate: u32,
+    #[serde(default, rename = "bitRate")]
+    bit_rate: u32,
+    #[serde(default)]
+    composer: String,
+    #[serde(default, rename = "albumArtist")]
+    album_artist: String,
+    #[serde(default)]
+    rating: u8,
+    #[serde(default)]
+    loved: bool,
+    #[serde(default)]
+    disliked: bool,
 }
 
-/// JXA 스크립트를 실행하고 결과를 반환합니다.
+/// 비ASCII 문자를 `\uXXXX`로 이스케이프하는 JSON 직렬화 함수 (JXA용)
+/// osascript 출력 인코딩과 무관하게 항상 ASCII만 출력되므로 특이한 유니코드가 섞인
+/// 트랙 이름도 깨지지 않고 파싱됩니다.
+const ASCII_JSON_FN: &str = r#"
+    function asciiJson(value) {
+        return JSON.stringify(value).replace(/[\u007f-\uffff]/g, function(c) {
+            return "\\u" + ("000" + c.charCodeAt(0).toString(16)).slice(-4);
+        });
+    }
+"#;
+
+/// osascript 응답 대기 시간 (Music.app이 멈춰도 UI가 같이 멈추지 않도록 넘으면 종료)
+const JXA_TIMEOUT: Duration = Duration::from_secs(2);
+/// 보관함 전체를 훑거나 큐를 만드는 스크립트의 응답 대기 시간
+const JXA_SLOW_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// osascript가 제한 시간 안에 응답하지 않은 경우의 에러
+#[derive(Debug)]
+pub struct JxaTimeout;
+
+impl std::fmt::Display for JxaTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "osascript 응답 시간 초과")
+    }
+}
+
+impl std::error::Error for JxaTimeout {}
+
+/// 상주 osascript 프로세스 사용 여부 (`persistent_osascript` 설정)
+static PERSISTENT_JXA: AtomicBool = AtomicBool::new(false);
+
+/// 상주 osascript 프로세스 (사용 중에만 Some)
+#[cfg(target_os = "macos")]
+static JXA_RUNNER: Mutex<Option<JxaRunner>> = Mutex::new(None);
+
+/// 상주 프로세스에서 실행할 JXA 서버 스크립트
+/// 한 줄에 하나씩 JSON 문자열로 받은 스크립트를 eval하고, 결과를 한 줄 JSON으로 돌려줍니다.
 #[cfg(target_os = "macos")]
+const JXA_SERVER_SCRIPT: &str = r#"
+    ObjC.import("Foundation");
+    const input = $.NSFileHandle.fileHandleWithStandardInput;
+    const output = $.NSFileHandle.fileHandleWithStandardOutput;
+    function runScript(__script) {
+        return eval(__script);
+    }
+    let buffer = "";
+    while (true) {
+        const data = input.availableData;
+        if (data.length === 0) break;
+        buffer += $.NSString.alloc.initWithDataEncoding(data, $.NSUTF8StringEncoding).js;
+        let newline;
+        while ((newline = buffer.indexOf("\n")) >= 0) {
+            const line = buffer.slice(0, newline);
+            buffer = buffer.slice(newline + 1);
+            let response;
+            try {
+                const value = runScript(JSON.parse(line));
+                response = {ok: true, result: value === undefined ? "" : String(value)};
+            } catch (e) {
+                response = {ok: false, result: String(e)};
+            }
+            output.writeData($(JSON.stringify(response) + "\n").dataUsingEncoding($.NSUTF8StringEncoding));
+        }
+    }
+"#;
+
+/// 상주 프로세스 응답
+#[cfg(target_os = "macos")]
+#[derive(Deserialize)]
+struct RunnerResponse {
+    ok: bool,
+    result: String,
+}
+
+/// 스크립트마다 osascript를 새로 띄우지 않고 하나의 프로세스에 파이프로 전달하는 실행기
+#[cfg(target_os = "macos")]
+pub struct JxaRunner {
+    child: std::process::Child,
+    stdin: std::process::ChildStdin,
+    /// 응답 줄 (제한 시간을 두고 기다릴 수 있도록 별도 스레드에서 읽음)
+    lines: Receiver<String>,
+}
+
+#[cfg(target_os = "macos")]
+impl JxaRunner {
+    /// 상주 osascript 프로세스 시작
+    pub fn spawn() -> Result<Self> {
+        let mut child = Command::new("osascript")
+            .env("LANG", "en_US.UTF-8")
+            .env("LC_ALL", "en_US.UTF-8")
+            .arg("-l")
+            .arg("JavaScript")
+            .arg("-e")
+            .arg(JXA_SERVER_SCRIPT)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .context("상주 osascript 실행 실패")?;
+        let stdin = child.stdin.take().context("osascript 입력 연결 실패")?;
+        let stdout = BufReader::new(child.stdout.take().context("osascript 출력 연결 실패")?);
+
+        let (tx, lines) = mpsc::channel();
+        std::thread::spawn(move || {
+            for line in stdout.lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if tx.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(JxaRunner { child, stdin, lines })
+    }
+
+    /// 스크립트 실행 (`timeout` 안에 응답이 없으면 프로세스를 종료하고 `JxaTimeout` 에러)
+    pub fn run(&mut self, script: &str, timeout: Duration) -> Result<String> {
+        // 읽는 쪽에서 UTF-8 조각이 잘리지 않도록 요청은 ASCII로만 보냄
+        let request = ascii_json(script)?;
+        writeln!(self.stdin, "{}", request).context("osascript에 스크립트 전달 실패")?;
+        self.stdin.flush().context("osascript에 스크립트 전달 실패")?;
+
+        let line = match self.lines.recv_timeout(timeout) {
+            Ok(line) => line,
+            // 늦게 온 응답이 다음 요청의 응답으로 읽히지 않도록 프로세스째 버림
+            Err(RecvTimeoutError::Timeout) => {
+                let _ = self.child.kill();
+                return Err(JxaTimeout.into());
+            }
+            Err(RecvTimeoutError::Disconnected) => bail!("상주 osascript가 종료됨"),
+        };
+        let response: RunnerResponse = serde_json::from_str(&line).context("osascript 응답 파싱 실패")?;
+        if !response.ok {
+            bail!("JXA 스크립트 실패: {}", response.result);
+        }
+        Ok(response.result.trim().to_string())
+    }
+
+    /// 프로세스가 아직 살아 있는지 여부
+    fn is_alive(&mut self) -> bool {
+        matches!(self.child.try_wait(), Ok(None))
+    }
+}
+
+#[cfg(target_os = "macos")]
+impl Drop for JxaRunner {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// 비ASCII 문자를 `\uXXXX`로 이스케이프한 JSON 문자열
+#[cfg(target_os = "macos")]
+fn ascii_json(value: &str) -> Result<String> {
+    let json = serde_json::to_string(value).context("스크립트 직렬화 실패")?;
+    let mut escaped = String::with_capacity(json.len());
+    for c in json.chars() {
+        if c.is_ascii() {
+            escaped.push(c);
+        } else {
+            let mut units = [0u16; 2];
+            for unit in c.encode_utf16(&mut units) {
+                escaped.push_str(&format!("\\u{:04x}", unit));
+            }
+        }
+    }
+    Ok(escaped)
+}
+
+/// 상주 osascript 프로세스 사용 설정 (끄면 실행 중인 프로세스 종료)
+pub fn set_persistent(enabled: bool) {
+    PERSISTENT_JXA.store(enabled, Ordering::Relaxed);
+    #[cfg(target_os = "macos")]
+    if !enabled && let Ok(mut runner) = JXA_RUNNER.lock() {
+        *runner = None;
+    }
+}
+
+/// JXA 스크립트를 실행하고 결과를 반환합니다 (`JXA_TIMEOUT` 안에 응답이 없으면 `JxaTimeout` 에러).
 fn run_jxa(script: &str) -> Result<String> {
-    let output = Command::new("osascript")
+    run_jxa_with_timeout(script, JXA_TIMEOUT)
+}
+
+/// 오래 걸릴 수 있는 스크립트 실행 (`JXA_SLOW_TIMEOUT`까지 대기)
+fn run_jxa_slow(script: &str) -> Result<String> {
+    run_jxa_with_timeout(script, JXA_SLOW_TIMEOUT)
+}
+
+/// 상주 프로세스를 쓰도록 설정했으면 먼저 시도하고, 프로세스가 죽었으면 한 번 실행 방식으로 폴백합니다.
+/// 백그라운드 검색 등 다른 스레드가 상주 프로세스를 쓰는 중이면 기다리지 않고 바로 한 번 실행합니다.
+#[cfg(target_os = "macos")]
+fn run_jxa_with_timeout(script: &str, timeout: Duration) -> Result<String> {
+    if PERSISTENT_JXA.load(Ordering::Relaxed)
+        && let Ok(mut guard) = JXA_RUNNER.try_lock()
+    {
+        if guard.is_none() {
+            *guard = JxaRunner::spawn().ok();
+        }
+        if let Some(runner) = guard.as_mut() {
+            match runner.run(script, timeout) {
+                Ok(result) => return Ok(result),
+                // 시간 초과면 다시 실행해도 멈출 가능성이 높으므로 폴백하지 않음
+                Err(e) if e.is::<JxaTimeout>() => {
+                    *guard = None;
+                    return Err(e);
+                }
+                // 스크립트 자체 에러면 그대로 반환
+                Err(e) if runner.is_alive() => return Err(e),
+                // 프로세스가 죽었으면 다음 호출에서 새로 시작
+                Err(_) => *guard = None,
+            }
+        }
+    }
+    run_jxa_once(script, timeout)
+}
+
+/// osascript를 한 번 실행해 스크립트 결과를 반환합니다.
+#[cfg(target_os = "macos")]
+fn run_jxa_once(script: &str, timeout: Duration) -> Result<String> {
+    let mut child = Command::new("osascript")
+        // 로케일이 없는 환경에서도 UTF-8로 출력하도록 강제
+        .env("LANG", "en_US.UTF-8")
+        .env("LC_ALL", "en_US.UTF-8")
         .arg("-l")
         .arg("JavaScript")
         .arg("-e")
         .arg(script)
-        .output()
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
         .context("osascript 실행 실패")?;
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
+    // 출력이 파이프 버퍼보다 커도 막히지 않도록 기다리는 동안 별도 스레드에서 읽음
+    let stdout = read_pipe(child.stdout.take());
+    let stderr = read_pipe(child.stderr.take());
+
+    let Some(status) = child.wait_timeout(timeout).context("osascript 대기 실패")? else {
+        let _ = child.kill();
+        let _ = child.wait();
+        return Err(JxaTimeout.into());
+    };
+
+    if !status.success() {
+        let stderr = stderr.join().unwrap_or_default();
         anyhow::bail!("JXA 스크립트 실패: {}", stderr);
     }
 
-    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    Ok(stdout.join().unwrap_or_default().trim().to_string())
+}
+
+/// 자식 프로세스 출력을 끝까지 읽는 스레드 시작
+#[cfg(target_os = "macos")]
+fn read_pipe<R: Read + Send + 'static>(pipe: Option<R>) -> std::thread::JoinHandle<String> {
+    std::thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
 }
 
 #[cfg(not(target_os = "macos"))]
-fn run_jxa(_script: &str) -> Result<String> {
+fn run_jxa_with_timeout(_script: &str, _timeout: Duration) -> Result<String> {
     anyhow::bail!("이 앱은 macOS에서만 실행됩니다.")
 }
 
@@ -83,10 +456,10 @@ pub fn is_music_running() -> bool {
 
 /// Music.app 실행 (백그라운드)
 pub fn launch_music() -> Result<()> {
-    run_jxa("Application('Music').activate()")?;
+    run_jxa_slow("Application('Music').activate()")?;
     // 잠시 대기 후 백그라운드로
     std::thread::sleep(std::time::Duration::from_millis(500));
-    run_jxa(r#"
+    run_jxa_slow(r#"
         Application('System Events').processes.byName('Music').windows[0].buttons[0].click()
     "#).ok(); // 창 닫기 시도 (실패해도 무시)
     Ok(())
@@ -100,44 +473,66 @@ pub fn ensure_music_ready() -> Result<()> {
     Ok(())
 }
 
-/// 라이브러리에서 재생 시작 (stopped 상태에서 호출)
-pub fn start_playback() -> Result<()> {
-    let script = r#"
+/// 설정된 시작 동작에 따라 재생 시작 (stopped 상태에서 호출)
+/// 선택한 동작으로 재생되지 않으면 라이브러리 첫 곡으로 폴백합니다.
+pub fn start_playback(action: StartAction, playlist: Option<&str>) -> Result<()> {
+    let action = match action {
+        // 목록에서 고르는 동작은 App에서 처리하므로 여기로 오면 첫 곡 재생
+        StartAction::Pick | StartAction::FirstTrack => "first_track",
+        StartAction::Random => "random",
+        StartAction::Playlist => "playlist",
+        StartAction::Resume => "resume",
+    };
+    let playlist_json = serde_json::to_string(&playlist).context("플레이리스트 이름 직렬화 실패")?;
+
+    let script = format!(r#"
         const music = Application('Music');
-        // 라이브러리 플레이리스트에서 첫 번째 곡 재생
-        try {
-            const library = music.libraryPlaylists[0];
-            if (library && library.tracks.length > 0) {
-                library.tracks[0].play();
-                "ok";
-            } else {
-                "no_tracks";
-            }
-        } catch(e) {
-            "error";
-        }
-    "#;
-    run_jxa(script)?;
+        const action = "{action}";
+        const playlistName = {playlist_json};
+        try {{
+            if (action === "resume") {{
+                // 마지막 재생 컨텍스트 이어서 재생
+                music.play();
+            }} else if (action === "playlist" && playlistName) {{
+                const playlists = music.playlists.whose({{name: playlistName}});
+                if (playlists.length > 0) {{
+                    playlists[0].play();
+                }}
+            }}
+
+            if (music.playerState() === 'stopped') {{
+                const library = music.libraryPlaylists[0];
+                const count = library ? library.tracks.length : 0;
+                if (count > 0) {{
+                    const index = action === "random" ? Math.floor(Math.random() * count) : 0;
+                    library.tracks[index].play();
+                }}
+            }}
+        }} catch(e) {{}}
+    "#);
+    run_jxa_slow(&script)?;
     Ok(())
 }
 
-/// 재생/일시정지 토글 (stopped면 재생 시작)
-pub fn play_pause() -> Result<()> {
+/// 재생/일시정지 토글
+/// stopped 상태면 아무것도 하지 않고 false를 반환합니다 (`start_playback`으로 시작).
+pub fn play_pause() -> Result<bool> {
     let script = r#"
         const music = Application('Music');
         if (music.playerState() === 'stopped') {
-            // stopped 상태면 라이브러리에서 재생 시작
-            try {
-                const library = music.libraryPlaylists[0];
-                if (library && library.tracks.length > 0) {
-                    library.tracks[0].play();
-                }
-            } catch(e) {}
+            "stopped";
         } else {
             music.playpause();
+            "ok";
         }
     "#;
-    run_jxa(script)?;
+    let result = run_jxa(script)?;
+    Ok(result != "stopped")
+}
+
+/// 일시정지
+pub fn pause() -> Result<()> {
+    run_jxa("Application('Music').pause()")?;
     Ok(())
 }
 
@@ -166,94 +561,602 @@ pub fn get_volume() -> Result<u8> {
     result.parse().context("볼륨 파싱 실패")
 }
 
-/// 현재 재생 중인 트랙 정보 가져오기
-pub fn get_current_track() -> Result<TrackInfo> {
+/// 셔플 상태 가져오기
+pub fn get_shuffle() -> Result<bool> {
+    let result = run_jxa("Application('Music').shuffleEnabled()")?;
+    Ok(result == "true")
+}
+
+/// 셔플 켜기/끄기
+pub fn set_shuffle(enabled: bool) -> Result<()> {
+    run_jxa(&format!("Application('Music').shuffleEnabled = {}", enabled))?;
+    Ok(())
+}
+
+/// 반복 모드 가져오기
+pub fn get_repeat() -> Result<RepeatMode> {
+    let result = run_jxa("Application('Music').songRepeat()")?;
+    Ok(RepeatMode::from(result.as_str()))
+}
+
+/// 반복 모드 설정
+pub fn set_repeat(mode: RepeatMode) -> Result<()> {
+    run_jxa(&format!("Application('Music').songRepeat = '{}'", mode.as_str()))?;
+    Ok(())
+}
+
+/// Music.app 별점(0-100, 별 하나에 20)을 별 개수로 변환
+fn rating_to_stars(rating: u8) -> u8 {
+    (rating.min(100) + 10) / 20
+}
+
+/// 현재 트랙 별점 가져오기 (0-5)
+pub fn get_rating() -> Result<u8> {
+    let result = run_jxa("Application('Music').currentTrack().rating()")?;
+    let rating: u8 = result.parse().context("별점 파싱 실패")?;
+    Ok(rating_to_stars(rating))
+}
+
+/// 현재 트랙 별점 설정 (0-5, 0이면 별점 지우기)
+pub fn set_rating(stars: u8) -> Result<()> {
+    run_jxa(&format!("Application('Music').currentTrack().rating = {}", stars.min(5) as u32 * 20))?;
+    Ok(())
+}
+
+/// 재생 위치 설정 (초)
+pub fn set_player_position(pos: f64) -> Result<()> {
+    run_jxa(&format!("Application('Music').playerPosition = {}", pos.max(0.0)))?;
+    Ok(())
+}
+
+/// 트랙 챕터 (오디오북/팟캐스트)
+#[derive(Debug, Clone, Deserialize)]
+pub struct Chapter {
+    pub name: String,
+    /// 챕터 시작 위치 (초)
+    pub start: f64,
+}
+
+/// 현재 트랙의 챕터 목록 (시작 위치 순)
+/// 챕터가 없거나 스크립팅으로 접근할 수 없으면 빈 목록을 반환합니다.
+pub fn get_chapters() -> Result<Vec<Chapter>> {
+    let script = format!(r#"
+        {ASCII_JSON_FN}
+        const music = Application("Music");
+        let output = [];
+        try {{
+            const chapters = music.currentTrack().chapters();
+            for (let i = 0; i < chapters.length; i++) {{
+                const chapter = chapters[i];
+                output.push({{
+                    name: chapter.name() || ("Chapter " + (i + 1)),
+                    start: Number(chapter.start()) || 0
+                }});
+            }}
+        }} catch(e) {{}}
+        asciiJson(output);
+    "#);
+
+    let result = run_jxa(&script)?;
+    let mut chapters: Vec<Chapter> = serde_json::from_str(&result).unwrap_or_default();
+    chapters.sort_by(|a, b| a.start.total_cmp(&b.start));
+    Ok(chapters)
+}
+
+/// 현재 트랙의 가사 가져오기 (없으면 None)
+pub fn get_lyrics() -> Result<Option<String>> {
+    let script = format!(r#"
+        {ASCII_JSON_FN}
+        let lyrics = "";
+        try {{
+            lyrics = Application("Music").currentTrack().lyrics() || "";
+        }} catch(e) {{}}
+        asciiJson(lyrics);
+    "#);
+
+    let result = run_jxa(&script)?;
+    let lyrics: String = serde_json::from_str(&result).context("가사 파싱 실패")?;
+    Ok(Some(lyrics).filter(|lyrics| !lyrics.trim().is_empty()))
+}
+
+/// 현재 트랙의 로컬 파일 경로 (스트리밍/클라우드 트랙이면 None)
+pub fn get_track_location() -> Result<Option<String>> {
+    let script = format!(r#"
+        {ASCII_JSON_FN}
+        let location = "";
+        try {{
+            const file = Application("Music").currentTrack().location();
+            if (file) location = file.toString();
+        }} catch(e) {{}}
+        asciiJson(location);
+    "#);
+
+    let result = run_jxa(&script)?;
+    let location: String = serde_json::from_str(&result).context("파일 경로 파싱 실패")?;
+    Ok(Some(location).filter(|location| !location.is_empty()))
+}
+
+/// 시스템 출력 볼륨 설정 (0-100)
+pub fn set_system_volume(level: u8) -> Result<()> {
+    let level = level.min(100);
+    let script = format!(r#"
+        const app = Application.currentApplication();
+        app.includeStandardAdditions = true;
+        app.setVolume(null, {{outputVolume: {}}});
+    "#, level);
+    run_jxa(&script)?;
+    Ok(())
+}
+
+/// 시스템 출력 볼륨 가져오기
+pub fn get_system_volume() -> Result<u8> {
+    let script = r#"
+        const app = Application.currentApplication();
+        app.includeStandardAdditions = true;
+        app.getVolumeSettings().outputVolume;
+    "#;
+    let result = run_jxa(script)?;
+    result.parse().context("시스템 볼륨 파싱 실패")
+}
+
+/// Music.app/시스템 볼륨 상태 (한 번의 호출로 조회)
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct VolumeState {
+    /// Music.app `soundVolume`
+    #[serde(rename = "app")]
+    pub app_volume: u8,
+    /// 시스템 출력 볼륨
+    #[serde(rename = "system")]
+    pub system_volume: u8,
+    /// 시스템 출력 음소거 여부
+    #[serde(rename = "systemMuted")]
+    pub system_muted: bool,
+}
+
+/// Music.app 볼륨과 시스템 출력 볼륨/음소거 상태를 함께 가져오기
+pub fn get_volume_state() -> Result<VolumeState> {
     let script = r#"
         const music = Application("Music");
+        const app = Application.currentApplication();
+        app.includeStandardAdditions = true;
+        const settings = app.getVolumeSettings();
+        JSON.stringify({
+            app: music.soundVolume(),
+            system: settings.outputVolume || 0,
+            systemMuted: settings.outputMuted === true
+        });
+    "#;
+    let result = run_jxa(script)?;
+    serde_json::from_str(&result).context("볼륨 상태 파싱 실패")
+}
+
+/// AirPlay 출력 장치
+#[derive(Debug, Clone, Deserialize)]
+pub struct AirPlayDevice {
+    pub name: String,
+    /// 장치 종류 (computer, HomePod, AirPort Express 등)
+    #[serde(default)]
+    pub kind: String,
+    /// 현재 출력 그룹에 포함되어 있는지 여부
+    #[serde(default)]
+    pub selected: bool,
+    /// 장치별 볼륨 (0-100)
+    #[serde(default)]
+    pub volume: u8,
+}
+
+/// 사용 가능한 AirPlay 출력 장치 목록 가져오기
+pub fn get_airplay_devices() -> Result<Vec<AirPlayDevice>> {
+    let script = format!(r#"
+        {ASCII_JSON_FN}
+        const music = Application("Music");
+        const output = [];
+        const devices = music.airplayDevices();
+        for (let i = 0; i < devices.length; i++) {{
+            const device = devices[i];
+            try {{
+                if (!device.available()) continue;
+                output.push({{
+                    name: device.name(),
+                    kind: String(device.kind() || ""),
+                    selected: device.selected() === true,
+                    volume: Number(device.soundVolume()) || 0
+                }});
+            }} catch(e) {{}}
+        }}
+        asciiJson(output);
+    "#);
+
+    let result = run_jxa(&script)?;
+    serde_json::from_str(&result).context("AirPlay 장치 목록 파싱 실패")
+}
+
+/// AirPlay 장치를 출력 그룹에 추가/제거
+pub fn set_airplay_device_selected(name: &str, selected: bool) -> Result<()> {
+    let name = serde_json::to_string(name).context("장치 이름 직렬화 실패")?;
+    run_jxa(&format!("Application('Music').airplayDevices.byName({}).selected = {}", name, selected))?;
+    Ok(())
+}
+
+/// 지정한 AirPlay 장치 하나만 출력으로 사용
+pub fn select_only_airplay_device(name: &str) -> Result<()> {
+    let name = serde_json::to_string(name).context("장치 이름 직렬화 실패")?;
+    let script = format!(r#"
+        const music = Application("Music");
+        music.currentAirplayDevices = [music.airplayDevices.byName({})];
+    "#, name);
+    run_jxa(&script)?;
+    Ok(())
+}
+
+/// AirPlay 장치별 볼륨 설정 (0-100)
+pub fn set_airplay_device_volume(name: &str, level: u8) -> Result<()> {
+    let name = serde_json::to_string(name).context("장치 이름 직렬화 실패")?;
+    run_jxa(&format!("Application('Music').airplayDevices.byName({}).soundVolume = {}", name, level.min(100)))?;
+    Ok(())
+}
+
+/// 현재 트랙의 좋아요 상태 설정 (좋아요를 켜면 싫어요는 해제)
+pub fn set_loved(loved: bool) -> Result<()> {
+    let script = format!(r#"
+        const track = Application('Music').currentTrack();
+        track.loved = {loved};
+        if ({loved}) {{
+            track.disliked = false;
+        }}
+    "#);
+    run_jxa(&script)?;
+    Ok(())
+}
+
+/// 현재 트랙의 싫어요 상태 설정 (싫어요를 켜면 좋아요는 해제)
+pub fn set_disliked(disliked: bool) -> Result<()> {
+    let script = format!(r#"
+        const track = Application('Music').currentTrack();
+        track.disliked = {disliked};
+        if ({disliked}) {{
+            track.loved = false;
+        }}
+    "#);
+    run_jxa(&script)?;
+    Ok(())
+}
+
+/// 현재 트랙 정보를 `RawTrackInfo` 형태의 객체로 읽는 JXA 함수
+const READ_TRACK_FN: &str = r#"
+    function readTrack(music) {
         const state = music.playerState();
         if (state === "stopped") {
-            JSON.stringify({
+            return {
+                id: "",
                 name: "",
                 artist: "",
                 album: "",
+                year: 0,
+                genre: "",
                 duration: 0,
                 playerPosition: 0,
-                state: "stopped"
-            });
-        } else {
-            const track = music.currentTrack();
-            JSON.stringify({
-                name: track.name(),
-                artist: track.artist(),
-                album: track.album(),
-                duration: track.duration(),
-                playerPosition: music.playerPosition(),
-                state: state
-            });
+                state: "stopped",
+                kind: "",
+                sampleRate: 0,
+                bitRate: 0,
+                composer: "",
+                albumArtist: "",
+                rating: 0,
+                loved: false,
+                disliked: false
+            };
         }
-    "#;
+        const track = music.currentTrack();
+        return {
+            id: track.persistentID(),
+            name: track.name(),
+            artist: track.artist(),
+            album: track.album(),
+            year: track.year(),
+            genre: track.genre() || "",
+            duration: track.duration(),
+            playerPosition: music.playerPosition(),
+            state: state,
+            kind: track.kind() || "",
+            sampleRate: track.sampleRate() || 0,
+            bitRate: track.bitRate() || 0,
+            composer: track.composer() || "",
+            albumArtist: track.albumArtist() || "",
+            rating: track.rating() || 0,
+            loved: track.loved() || false,
+            disliked: track.disliked() || false
+        };
+    }
+"#;
 
-    let result = run_jxa(script)?;
-    let raw: RawTrackInfo = serde_json::from_str(&result).context("트랙 정보 파싱 실패")?;
-
-    Ok(TrackInfo {
-        name: raw.name,
-        artist: raw.artist,
-        album: raw.album,
-        duration: raw.duration,
-        player_position: raw.player_position,
-        state: PlayerState::from(raw.state.as_str()),
+impl From<RawTrackInfo> for TrackInfo {
+    fn from(raw: RawTrackInfo) -> Self {
+        TrackInfo {
+            id: raw.id,
+            name: raw.name,
+            artist: raw.artist,
+            album: raw.album,
+            year: raw.year,
+            genre: raw.genre,
+            duration: raw.duration,
+            player_position: raw.player_position,
+            state: PlayerState::from(raw.state.as_str()),
+            kind: raw.kind,
+            sample_rate: raw.sample_rate,
+            bit_rate: raw.bit_rate,
+            composer: raw.composer,
+            album_artist: raw.album_artist,
+            rating: rating_to_stars(raw.rating),
+            loved: raw.loved,
+            disliked: raw.disliked,
+        }
+    }
+}
+
+/// 현재 재생 중인 트랙 정보 가져오기
+pub fn get_current_track() -> Result<TrackInfo> {
+    let script = format!(r#"
+        {ASCII_JSON_FN}
+        {READ_TRACK_FN}
+        asciiJson(readTrack(Application("Music")));
+    "#);
+
+    let result = run_jxa(&script)?;
+    let raw: RawTrackInfo = match serde_json::from_str(&result) {
+        Ok(raw) => raw,
+        // 출력이 일시적으로 깨졌을 수 있으므로 한 번 더 시도
+        Err(_) => {
+            let retry = run_jxa(&script)?;
+            serde_json::from_str(&retry).context("트랙 정보 파싱 실패")?
+        }
+    };
+
+    Ok(TrackInfo::from(raw))
+}
+
+/// 이퀄라이저 상태
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct EqState {
+    pub enabled: bool,
+    /// 현재 프리셋 이름 (꺼져 있어도 마지막으로 고른 프리셋)
+    pub preset: String,
+}
+
+/// 한 번의 스크립트로 읽은 플레이어 상태 (읽지 못한 항목은 None)
+#[derive(Debug, Clone, Default)]
+pub struct PlayerStatus {
+    /// Music.app 실행 여부 (false면 나머지는 모두 None)
+    pub running: bool,
+    pub track: Option<TrackInfo>,
+    pub shuffle: Option<bool>,
+    pub repeat: Option<RepeatMode>,
+    pub volume: Option<VolumeState>,
+    pub eq: Option<EqState>,
+}
+
+/// `get_player_status` 스크립트 출력
+#[derive(Deserialize)]
+struct RawPlayerStatus {
+    running: bool,
+    track: Option<RawTrackInfo>,
+    shuffle: Option<bool>,
+    repeat: Option<String>,
+    volume: Option<VolumeState>,
+    eq: Option<EqState>,
+}
+
+/// 트랙 정보, 셔플/반복, 볼륨을 osascript 한 번으로 함께 가져오기 (폴링용)
+/// 항목별로 실패를 분리하므로 일부를 읽지 못해도 나머지는 반환합니다.
+/// Music.app이 실행 중이 아니면 다시 실행되지 않도록 건드리지 않습니다.
+pub fn get_player_status() -> Result<PlayerStatus> {
+    let script = format!(r#"
+        {ASCII_JSON_FN}
+        {READ_TRACK_FN}
+        function attempt(read) {{
+            try {{
+                return read();
+            }} catch(e) {{
+                return null;
+            }}
+        }}
+        const running = Application("System Events").processes.whose({{name: "Music"}}).length > 0;
+        if (!running) {{
+            asciiJson({{ running: false }});
+        }} else {{
+            const music = Application("Music");
+            const app = Application.currentApplication();
+            app.includeStandardAdditions = true;
+            asciiJson({{
+                running: true,
+                track: attempt(() => readTrack(music)),
+                shuffle: attempt(() => music.shuffleEnabled()),
+                repeat: attempt(() => music.songRepeat()),
+                volume: attempt(() => {{
+                    const settings = app.getVolumeSettings();
+                    return {{
+                        app: music.soundVolume(),
+                        system: settings.outputVolume || 0,
+                        systemMuted: settings.outputMuted === true
+                    }};
+                }}),
+                eq: attempt(() => ({{
+                    enabled: music.eqEnabled(),
+                    preset: music.currentEQPreset().name()
+                }}))
+            }});
+        }}
+    "#);
+
+    let result = run_jxa(&script)?;
+    let raw: RawPlayerStatus = serde_json::from_str(&result).context("플레이어 상태 파싱 실패")?;
+    Ok(PlayerStatus {
+        running: raw.running,
+        track: raw.track.map(TrackInfo::from),
+        shuffle: raw.shuffle,
+        repeat: raw.repeat.as_deref().map(RepeatMode::from),
+        volume: raw.volume,
+        eq: raw.eq,
     })
 }
 
-/// 현재 트랙의 아트워크를 iTunes Search API로 가져와 임시 파일에 저장합니다.
-/// 아트워크가 없거나 가져올 수 없으면 None을 반환합니다.
-pub fn get_artwork_path() -> Result<Option<PathBuf>> {
-    // 먼저 현재 트랙 정보 가져오기
-    let track = get_current_track()?;
-    
+/// iTunes Search API로 트랙의 앨범 아트워크 후보 URL들을 검색합니다.
+/// 검색 결과 순서대로 최대 `limit`개의 고해상도 URL을 반환합니다.
+pub fn get_artwork_urls(track: &TrackInfo, limit: usize) -> Result<Vec<String>> {
     if track.name.is_empty() || track.artist.is_empty() {
-        return Ok(None);
+        return Ok(Vec::new());
     }
 
     // iTunes Search API로 아트워크 URL 검색
     let search_term = format!("{} {}", track.artist, track.album);
     let encoded_term = urlencoding(&search_term);
     let api_url = format!(
-        "https://itunes.apple.com/search?term={}&entity=album&limit=1",
-        encoded_term
+        "https://itunes.apple.com/search?term={}&entity=album&limit={}",
+        encoded_term, limit
     );
 
-    // curl로 API 호출
-    let output = std::process::Command::new("curl")
-        .args(["-s", &api_url])
-        .output()
-        .context("curl 실행 실패")?;
+    // API 호출 (일시적인 네트워크 오류나 요청 제한이면 잠시 뒤 재시도)
+    let json = with_retries(|| parse_itunes_response(&http_get(&api_url)?))?;
+
+    // JSON에서 artworkUrl100 추출 후 100x100을 600x600으로 변경하여 고해상도 이미지 사용
+    Ok(extract_artwork_urls(&json)
+        .into_iter()
+        .map(|url| url.replace("100x100", "600x600"))
+        .collect())
+}
+
+/// 아트워크 임시 파일 이름 접두사
+const ARTWORK_FILE_PREFIX: &str = "apple_music_tui_artwork";
+/// Music.app에서 꺼낸 내장 아트워크 임시 파일 이름 접두사 (JPEG/PNG일 수 있음)
+const EMBEDDED_ARTWORK_FILE_PREFIX: &str = "apple_music_tui_embedded_artwork";
+
+/// 트랙별 임시 파일 이름 (이름/아티스트/후보 URL 해시)
+/// 다운로드가 중간에 실패해도 이전 트랙의 이미지가 대신 보이지 않고,
+/// 같은 트랙의 여러 후보를 동시에 받아도 서로 덮어쓰지 않습니다.
+fn track_file_name(prefix: &str, track: &TrackInfo, url: &str, extension: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    (&track.name, &track.artist, url).hash(&mut hasher);
+    format!("{}_{:016x}{}", prefix, hasher.finish(), extension)
+}
 
-    if !output.status.success() {
+/// 아트워크를 저장할 수 있는 경로 찾기
+/// 시스템 임시 디렉토리에 쓸 수 없으면 `~/.cache/apple-music-tui`로 폴백합니다.
+fn writable_artwork_path(file_name: &str) -> Option<PathBuf> {
+    let mut dirs = vec![std::env::temp_dir()];
+    if let Some(home) = std::env::var_os("HOME") {
+        dirs.push(PathBuf::from(home).join(".cache").join("apple-music-tui"));
+    }
+
+    dirs.into_iter().find_map(|dir| {
+        std::fs::create_dir_all(&dir).ok()?;
+        let path = dir.join(file_name);
+        // 실제로 파일을 열어 쓰기 가능 여부 확인
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .ok()
+            .map(|_| path)
+    })
+}
+
+/// 현재 트랙에 내장된 아트워크를 Music.app에서 꺼내 트랙별 임시 파일에 저장합니다.
+/// 아트워크가 없거나 저장할 위치가 없으면 None을 반환합니다.
+pub fn get_embedded_artwork(track: &TrackInfo) -> Result<Option<PathBuf>> {
+    let file_name = track_file_name(EMBEDDED_ARTWORK_FILE_PREFIX, track, "", "");
+    let Some(temp_path) = writable_artwork_path(&file_name) else {
         return Ok(None);
+    };
+    let path_json = serde_json::to_string(&temp_path.to_string_lossy()).context("아트워크 경로 직렬화 실패")?;
+
+    let script = format!(r#"
+        const music = Application("Music");
+        const app = Application.currentApplication();
+        app.includeStandardAdditions = true;
+        let result = "none";
+        try {{
+            const artworks = music.currentTrack().artworks();
+            if (artworks.length > 0) {{
+                const data = artworks[0].rawData();
+                const file = app.openForAccess(Path({path_json}), {{writePermission: true}});
+                try {{
+                    app.setEof(file, {{to: 0}});
+                    app.write(data, {{to: file}});
+                    result = "ok";
+                }} finally {{
+                    app.closeAccess(file);
+                }}
+            }}
+        }} catch(e) {{}}
+        result;
+    "#);
+
+    let result = run_jxa_slow(&script);
+    if !matches!(result.as_deref(), Ok("ok")) {
+        // 쓰기 확인용으로 만든 빈 파일 정리
+        let _ = std::fs::remove_file(&temp_path);
     }
+    Ok((result? == "ok").then_some(temp_path))
+}
 
-    let response = String::from_utf8_lossy(&output.stdout);
-    
-    // JSON에서 artworkUrl100 추출
-    if let Some(artwork_url) = extract_artwork_url(&response) {
-        // 100x100을 600x600으로 변경하여 고해상도 이미지 가져오기
-        let hires_url = artwork_url.replace("100x100", "600x600");
-        
-        // 이미지 다운로드
-        let temp_path = std::env::temp_dir().join("apple_music_tui_artwork.jpg");
-        let download = std::process::Command::new("curl")
-            .args(["-s", "-o", temp_path.to_str().unwrap(), &hires_url])
-            .output()
-            .context("아트워크 다운로드 실패")?;
+/// 아트워크 이미지를 다운로드해 트랙별 임시 파일에 저장합니다.
+/// 저장할 위치가 없으면 None, 재시도 후에도 받지 못하면 에러를 반환합니다 (받다 만 파일은 삭제).
+/// 같은 트랙/URL로 이미 받아 둔 파일이 남아 있으면 다시 받지 않습니다.
+pub fn download_artwork(url: &str, track: &TrackInfo) -> Result<Option<PathBuf>> {
+    let file_name = track_file_name(ARTWORK_FILE_PREFIX, track, url, ".jpg");
+    let Some(temp_path) = writable_artwork_path(&file_name) else {
+        return Ok(None);
+    };
+    // 쓰기 확인용으로 만든 빈 파일과 구분
+    if std::fs::metadata(&temp_path).is_ok_and(|meta| meta.len() > 0) {
+        return Ok(Some(temp_path));
+    }
 
-        if download.status.success() && temp_path.exists() {
-            return Ok(Some(temp_path));
+    match with_retries(|| download_to(url, &temp_path)) {
+        Ok(()) => Ok(Some(temp_path)),
+        Err(e) => {
+            let _ = std::fs::remove_file(&temp_path);
+            Err(e)
         }
     }
+}
+
+/// URL을 파일로 다운로드 (시간 초과면 `HttpTimeout` 에러)
+fn download_to(url: &str, path: &Path) -> Result<()> {
+    let bytes = http_get_bytes(url).context("아트워크 다운로드 실패")?;
+    std::fs::write(path, bytes).context("아트워크 파일 저장 실패")
+}
+
+/// 아트워크 요청 시도 횟수 (첫 시도 포함)
+const ARTWORK_ATTEMPTS: u32 = 3;
+/// 첫 재시도 전 대기 시간 (재시도할 때마다 두 배)
+const ARTWORK_RETRY_DELAY: Duration = Duration::from_millis(500);
 
-    Ok(None)
+/// 일시적인 실패면 대기 시간을 늘려 가며 `ARTWORK_ATTEMPTS`번까지 시도 (마지막 에러 반환)
+/// 4xx나 응답 파싱 실패처럼 다시 보내도 같은 결과일 에러는 바로 반환합니다.
+fn with_retries<T>(mut request: impl FnMut() -> Result<T>) -> Result<T> {
+    let mut delay = ARTWORK_RETRY_DELAY;
+    for _ in 1..ARTWORK_ATTEMPTS {
+        match request() {
+            Ok(value) => return Ok(value),
+            Err(e) if !is_transient(&e) => return Err(e),
+            Err(_) => {
+                std::thread::sleep(delay);
+                delay *= 2;
+            }
+        }
+    }
+    request()
+}
+
+/// 다시 시도하면 성공할 수 있는 에러인지 (시간 초과, 연결 실패, 서버 5xx)
+fn is_transient(error: &anyhow::Error) -> bool {
+    if error.is::<HttpTimeout>() {
+        return true;
+    }
+    error.downcast_ref::<reqwest::Error>().is_some_and(|e| {
+        e.is_timeout() || e.is_connect() || e.status().is_some_and(|status| status.is_server_error())
+    })
 }
 
 /// URL 인코딩 (간단한 구현)
@@ -273,86 +1176,187 @@ fn urlencoding(s: &str) -> String {
     result
 }
 
-/// JSON 응답에서 artworkUrl100 추출
-fn extract_artwork_url(json: &str) -> Option<String> {
-    // "artworkUrl100":"URL" 패턴 찾기
-    let marker = "\"artworkUrl100\":\"";
-    if let Some(start) = json.find(marker) {
-        let start_idx = start + marker.len();
-        if let Some(end) = json[start_idx..].find('"') {
-            return Some(json[start_idx..start_idx + end].to_string());
-        }
-    }
-    None
+/// iTunes API 응답의 각 결과에서 artworkUrl100 추출 (결과 순서대로, 없는 항목은 건너뜀)
+fn extract_artwork_urls(json: &serde_json::Value) -> Vec<String> {
+    json["results"]
+        .as_array()
+        .map(|results| {
+            results
+                .iter()
+                .filter_map(|item| item["artworkUrl100"].as_str())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
 }
 
-
 /// 검색 결과
 #[derive(Debug, Clone, Deserialize)]
 pub struct SearchResult {
     pub name: String,
     pub artist: String,
     pub album: String,
+    /// 발매 연도 (알 수 없으면 0)
+    #[serde(default)]
+    pub year: u32,
+    /// 재생 시간 (초)
+    #[serde(default)]
+    pub duration: f64,
+    /// BPM (알 수 없으면 0)
+    #[serde(default)]
+    pub bpm: u32,
     pub id: String, // persistentID
+    /// 중복 제거로 합쳐진 추가 사본 수 (0이면 중복 없음)
+    #[serde(skip)]
+    pub duplicates: usize,
+    /// 결과 출처 (보관함 / Apple Music 카탈로그)
+    #[serde(skip)]
+    pub source: ResultSource,
 }
 
-/// 라이브러리 검색
-pub fn search_library(query: &str) -> Result<Vec<SearchResult>> {
-    // 따옴표 escaping
-    let safe_query = query.replace('"', "\\\"");
+/// 검색 결과 출처
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ResultSource {
+    #[default]
+    Library,
+    AppleMusic,
+}
+
+/// 라이브러리 검색 (`playlist_id`를 주면 그 플레이리스트 안에서만 검색)
+/// `broad`면 검색어 전체와 단어(3글자 이상)별 결과를 합쳐 최대 `limit`개까지 가져옵니다.
+/// Music.app 검색은 부분 문자열 일치라 오타가 있으면 결과가 없으므로, 퍼지 순위를 매기기 전 후보를 넓히는 용도입니다.
+pub fn search_library(query: &str, playlist_id: Option<&str>, broad: bool, limit: usize) -> Result<Vec<SearchResult>> {
+    let mut terms = vec![query.to_string()];
+    if broad {
+        terms.extend(query.split_whitespace().filter(|word| word.chars().count() >= 3).map(str::to_string));
+    }
+    let terms_json = serde_json::to_string(&terms).context("검색어 직렬화 실패")?;
+    let playlist_json = serde_json::to_string(&playlist_id).context("플레이리스트 ID 직렬화 실패")?;
     
     let script = format!(r#"
+        {ASCII_JSON_FN}
         const music = Application("Music");
-        const library = music.libraryPlaylists[0];
+        const playlistId = {playlist_json};
+        const terms = {terms_json};
+        const limit = {limit};
         
         try {{
-            // 검색 수행
-            const results = music.search(library, {{for: "{safe_query}"}});
-            
-            // 결과 매핑 (최대 20개까지만)
+            // 검색 범위: 지정한 플레이리스트 또는 보관함 전체 (이름은 겹칠 수 있으므로 ID로 찾음)
+            const source = playlistId === null
+                ? music.libraryPlaylists[0]
+                : music.userPlaylists.whose({{persistentID: playlistId}})[0];
+
+            // 검색어마다 검색해 중복 없이 최대 limit개까지 매핑
             let output = [];
-            const limit = Math.min(results.length, 20);
-            
-            for (let i = 0; i < limit; i++) {{
-                const track = results[i];
-                output.push({{
-                    name: track.name(),
-                    artist: track.artist(),
-                    album: track.album(),
-                    id: track.persistentID()
-                }});
+            const seen = {{}};
+            for (const term of terms) {{
+                const results = music.search(source, {{for: term}});
+                for (let i = 0; i < results.length && output.length < limit; i++) {{
+                    const track = results[i];
+                    const id = track.persistentID();
+                    if (seen[id]) continue;
+                    seen[id] = true;
+                    output.push({{
+                        name: track.name(),
+                        artist: track.artist(),
+                        album: track.album(),
+                        year: track.year(),
+                        duration: track.duration(),
+                        bpm: track.bpm(),
+                        id: id
+                    }});
+                }}
+                if (output.length >= limit) break;
             }}
             
-            JSON.stringify(output);
+            asciiJson(output);
         }} catch(e) {{
-            JSON.stringify([]);
+            asciiJson([]);
         }}
     "#);
 
-    let result = run_jxa(&script)?;
+    let result = run_jxa_slow(&script)?;
     let search_results: Vec<SearchResult> = serde_json::from_str(&result).unwrap_or_default();
     
     Ok(search_results)
 }
 
 
+/// iTunes Search API 응답 검증 및 파싱
+/// 요청 제한이나 캡티브 포털 때문에 HTML 페이지가 돌아오면 빈 결과 대신 에러 반환
+fn parse_itunes_response(body: &str) -> Result<serde_json::Value> {
+    let json: serde_json::Value = serde_json::from_str(body.trim())
+        .with_context(|| format!("iTunes API 응답이 JSON이 아님: {}", body.trim().chars().take(60).collect::<String>()))?;
+    if !json["results"].is_array() {
+        bail!("iTunes API 응답에 results가 없음");
+    }
+    Ok(json)
+}
+
+/// HTTP 요청 제한 시간 (초)
+static HTTP_TIMEOUT_SECS: AtomicU64 = AtomicU64::new(5);
+
+/// HTTP 요청 시간 초과 에러 (`err.is::<HttpTimeout>()`로 구분)
+#[derive(Debug)]
+pub struct HttpTimeout;
+
+impl std::fmt::Display for HttpTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HTTP 요청 시간 초과")
+    }
+}
+
+impl std::error::Error for HttpTimeout {}
+
+/// 아트워크/검색 HTTP 요청 제한 시간 설정 (초)
+pub fn set_http_timeout(secs: u64) {
+    HTTP_TIMEOUT_SECS.store(secs.max(1), Ordering::Relaxed);
+}
+
+/// 모든 HTTP 요청이 함께 쓰는 클라이언트 (연결 재사용, 제한 시간은 요청마다 지정)
+static HTTP_CLIENT: OnceLock<reqwest::blocking::Client> = OnceLock::new();
+
+/// URL 본문을 바이트로 가져오기 (시간 초과면 `HttpTimeout`, 2xx가 아니면 에러)
+/// blocking 클라이언트는 tokio 런타임 안(UI 루프)에서 쓰면 패닉하므로 그때만 별도 스레드에서 요청합니다.
+fn http_get_bytes(url: &str) -> Result<Vec<u8>> {
+    let timeout = Duration::from_secs(HTTP_TIMEOUT_SECS.load(Ordering::Relaxed));
+    let request = || -> reqwest::Result<Vec<u8>> {
+        let client = match HTTP_CLIENT.get() {
+            Some(client) => client,
+            None => {
+                let client = reqwest::blocking::Client::builder().build()?;
+                HTTP_CLIENT.get_or_init(|| client)
+            }
+        };
+        let response = client.get(url).timeout(timeout).send()?.error_for_status()?;
+        Ok(response.bytes()?.to_vec())
+    };
+    let result = if tokio::runtime::Handle::try_current().is_ok() {
+        std::thread::scope(|scope| scope.spawn(request).join())
+            .map_err(|_| anyhow::anyhow!("HTTP 요청 스레드 패닉: {}", url))?
+    } else {
+        request()
+    };
+    match result {
+        Ok(bytes) => Ok(bytes),
+        Err(e) if e.is_timeout() => Err(HttpTimeout.into()),
+        Err(e) => Err(anyhow::Error::new(e).context(format!("HTTP 요청 실패: {}", url))),
+    }
+}
+
+/// URL 본문 가져오기 (시간 초과면 `HttpTimeout` 에러)
+fn http_get(url: &str) -> Result<String> {
+    Ok(String::from_utf8_lossy(&http_get_bytes(url)?).into_owned())
+}
+
 /// Apple Music 카탈로그 검색 (iTunes Search API)
 pub fn search_apple_music(query: &str) -> Result<Vec<SearchResult>> {
     let encoded_query = urlencoding(query);
     let url = format!("https://itunes.apple.com/search?term={}&entity=song&limit=20&country=US", encoded_query); // country=KR? US가 안전
 
-    let output = std::process::Command::new("curl")
-        .args(["-s", &url])
-        .output()
-        .context("curl 실행 실패")?;
+    let response = http_get(&url)?;
+    let json = parse_itunes_response(&response)?;
 
-    if !output.status.success() {
-        return Ok(Vec::new());
-    }
-
-    let response = String::from_utf8_lossy(&output.stdout);
-    let json: serde_json::Value = serde_json::from_str(&response).unwrap_or(serde_json::json!({}));
-    
     let mut results = Vec::new();
     
     if let Some(items) = json["results"].as_array() {
@@ -360,6 +1364,8 @@ pub fn search_apple_music(query: &str) -> Result<Vec<SearchResult>> {
             let name = item["trackName"].as_str().unwrap_or("Unknown").to_string();
             let artist = item["artistName"].as_str().unwrap_or("Unknown").to_string();
             let album = item["collectionName"].as_str().unwrap_or("Unknown").to_string();
+            let year = item["releaseDate"].as_str().map(parse_release_year).unwrap_or(0);
+            let duration = item["trackTimeMillis"].as_f64().unwrap_or(0.0) / 1000.0;
             
             // trackViewUrl 또는 ID 조합
             // 재생을 위해서는 music:// 스킴 사용
@@ -380,7 +1386,12 @@ pub fn search_apple_music(query: &str) -> Result<Vec<SearchResult>> {
                 name,
                 artist,
                 album,
+                year,
+                duration,
+                bpm: 0,
                 id,
+                duplicates: 0,
+                source: ResultSource::AppleMusic,
             });
         }
     }
@@ -388,6 +1399,11 @@ pub fn search_apple_music(query: &str) -> Result<Vec<SearchResult>> {
     Ok(results)
 }
 
+/// iTunes API의 releaseDate (예: "2019-05-10T07:00:00Z")에서 연도 추출
+fn parse_release_year(date: &str) -> u32 {
+    date.get(..4).and_then(|year| year.parse().ok()).unwrap_or(0)
+}
+
 /// 트랙 재생 (ID 또는 Apple Music URL)
 /// 현재 활성 애플리케이션 이름 가져오기
 pub fn get_frontmost_application_name() -> Result<String> {
@@ -413,10 +1429,10 @@ pub fn play_track_by_id(id: &str) -> Result<()> {
             if let Some(mut stdin) = child.stdin.take() {
                 let _ = stdin.write_all(id.as_bytes());
             }
-            if let Ok(status) = child.wait() {
-                if status.success() {
-                    shortcut_success = true;
-                }
+            if let Ok(status) = child.wait()
+                && status.success()
+            {
+                shortcut_success = true;
             }
         }
 
@@ -473,5 +1489,485 @@ pub fn play_track_by_id(id: &str) -> Result<()> {
     Ok(())
 }
 
+/// 라이브러리에서 앨범 트랙 목록 가져오기 (디스크/트랙 번호 순)
+/// 컴필레이션을 위해 트랙 아티스트 또는 앨범 아티스트가 일치하면 포함합니다.
+pub fn get_album_tracks(artist: &str, album: &str) -> Result<Vec<SearchResult>> {
+    let artist_json = serde_json::to_string(artist).context("아티스트 직렬화 실패")?;
+    let album_json = serde_json::to_string(album).context("앨범 직렬화 실패")?;
+
+    let script = format!(r#"
+        {ASCII_JSON_FN}
+        const music = Application("Music");
+        const artist = {artist_json};
+        let output = [];
+        try {{
+            const tracks = music.libraryPlaylists[0].tracks.whose({{album: {album_json}}})();
+            const matched = tracks.filter(t => t.artist() === artist || t.albumArtist() === artist);
+            matched.sort((a, b) => (a.discNumber() - b.discNumber()) || (a.trackNumber() - b.trackNumber()));
+            output = matched.map(t => ({{
+                name: t.name(),
+                artist: t.artist(),
+                album: t.album(),
+                year: t.year(),
+                duration: t.duration(),
+                bpm: t.bpm(),
+                id: t.persistentID()
+            }}));
+        }} catch(e) {{}}
+        asciiJson(output);
+    "#);
 
+    let result = run_jxa_slow(&script)?;
+    Ok(serde_json::from_str(&result).unwrap_or_default())
+}
+
+/// 라이브러리에서 아티스트/앨범이 일치하는 트랙을 디스크/트랙 번호 순으로 재생
+/// 재생한 트랙 수를 반환 (일치하는 앨범이 없으면 0)
+pub fn play_album(artist: &str, album: &str) -> Result<usize> {
+    let ids: Vec<String> = get_album_tracks(artist, album)?.into_iter().map(|track| track.id).collect();
+    if ids.is_empty() {
+        return Ok(0);
+    }
+    play_tracks_as_queue(&ids)?;
+    Ok(ids.len())
+}
+
+/// 재생 대기열
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UpNext {
+    /// 셔플이 켜져 있어 다음 곡 순서를 알 수 없는지 (이때 `tracks`는 비어 있음)
+    pub shuffled: bool,
+    /// 다음에 재생될 트랙 (첫 항목은 현재 곡)
+    pub tracks: Vec<SearchResult>,
+}
+
+/// 다음에 재생될 트랙 목록
+/// 현재 재생 중인 플레이리스트 순서를 기준으로 하며, 읽을 수 없는 재생 소스면 에러를 반환합니다.
+/// 셔플 중에는 실제 재생 순서를 스크립트로 알 수 없으므로 목록 대신 `shuffled`만 표시합니다.
+pub fn get_up_next(limit: usize) -> Result<UpNext> {
+    let script = format!(r#"
+        {ASCII_JSON_FN}
+        const music = Application("Music");
+        const limit = {limit};
+        let output = null;
+        try {{
+            const id = music.currentTrack().persistentID();
+            const tracks = music.currentPlaylist().tracks;
+            const ids = tracks.persistentID();
+            const start = ids.indexOf(id);
+            if (music.shuffleEnabled()) {{
+                output = {{shuffled: true, tracks: []}};
+            }} else if (start >= 0) {{
+                output = {{shuffled: false, tracks: []}};
+                for (let i = start; i < ids.length && output.tracks.length < limit; i++) {{
+                    const t = tracks[i];
+                    output.tracks.push({{
+                        name: t.name(),
+                        artist: t.artist(),
+                        album: t.album(),
+                        year: t.year(),
+                        duration: t.duration(),
+                        bpm: t.bpm(),
+                        id: ids[i]
+                    }});
+                }}
+            }}
+        }} catch(e) {{}}
+        asciiJson(output);
+    "#);
+
+    let result = run_jxa_slow(&script)?;
+    let up_next: Option<UpNext> = serde_json::from_str(&result).context("재생 대기열 파싱 실패")?;
+    up_next.context("재생 대기열을 읽을 수 없음")
+}
 
+/// 끝난 트랙과 비슷한 보관함 트랙 ID 목록 (같은 아티스트 우선, 부족하면 같은 장르)
+pub fn get_similar_tracks(track: &TrackInfo, limit: usize) -> Result<Vec<String>> {
+    let id_json = serde_json::to_string(&track.id).context("트랙 ID 직렬화 실패")?;
+    let artist_json = serde_json::to_string(&track.artist).context("아티스트 직렬화 실패")?;
+
+    let script = format!(r#"
+        {ASCII_JSON_FN}
+        const music = Application("Music");
+        const library = music.libraryPlaylists[0];
+        const id = {id_json};
+        const limit = {limit};
+        const shuffle = (items) => {{
+            for (let i = items.length - 1; i > 0; i--) {{
+                const j = Math.floor(Math.random() * (i + 1));
+                [items[i], items[j]] = [items[j], items[i]];
+            }}
+            return items;
+        }};
+
+        let output = [];
+        try {{
+            const byArtist = shuffle(library.tracks.whose({{artist: {artist_json}}}).persistentID());
+            output = byArtist.filter(t => t !== id).slice(0, limit);
+
+            const source = library.tracks.whose({{persistentID: id}});
+            const genre = source.length > 0 ? source[0].genre() : "";
+            if (output.length < limit && genre) {{
+                const byGenre = shuffle(library.tracks.whose({{genre: genre}}).persistentID());
+                for (const t of byGenre) {{
+                    if (output.length >= limit) break;
+                    if (t !== id && !output.includes(t)) output.push(t);
+                }}
+            }}
+        }} catch(e) {{}}
+        asciiJson(output);
+    "#);
+
+    let result = run_jxa_slow(&script)?;
+    Ok(serde_json::from_str(&result).unwrap_or_default())
+}
+
+/// 보관함 사용자 플레이리스트
+#[derive(Debug, Clone, Deserialize)]
+pub struct Playlist {
+    pub name: String,
+    /// Music.app persistentID
+    pub id: String,
+}
+
+/// 보관함 사용자 플레이리스트 목록 (큐용 임시 플레이리스트 제외)
+pub fn get_playlists() -> Result<Vec<Playlist>> {
+    let queue_json = serde_json::to_string(&queue_playlist_id()).context("큐 플레이리스트 ID 직렬화 실패")?;
+    let script = format!(r#"
+        {ASCII_JSON_FN}
+        const queueId = {queue_json};
+        const playlists = Application("Music").userPlaylists;
+        const names = playlists.name();
+        const ids = playlists.persistentID();
+        const output = names
+            .map((name, i) => ({{ name: name, id: ids[i] }}))
+            .filter(p => p.id !== queueId);
+        asciiJson(output);
+    "#);
+
+    let result = run_jxa(&script)?;
+    serde_json::from_str(&result).context("플레이리스트 목록 파싱 실패")
+}
+
+/// 보관함 통계
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct LibraryStats {
+    /// 보관함 전체 트랙 수
+    pub tracks: usize,
+    /// 전체 재생 시간 (초)
+    #[serde(rename = "totalTime")]
+    pub total_time: f64,
+    /// 사용자 플레이리스트 수 (큐용 임시 플레이리스트 제외)
+    pub playlists: usize,
+}
+
+/// 보관함 트랙 수, 전체 재생 시간, 플레이리스트 수 (큰 보관함에서는 몇 초 걸릴 수 있음)
+pub fn get_library_stats() -> Result<LibraryStats> {
+    let queue_json = serde_json::to_string(&queue_playlist_id()).context("큐 플레이리스트 ID 직렬화 실패")?;
+    let script = format!(r#"
+        {ASCII_JSON_FN}
+        const music = Application("Music");
+        const queueId = {queue_json};
+        const tracks = music.libraryPlaylists[0].tracks;
+        const durations = tracks.duration();
+        asciiJson({{
+            tracks: durations.length,
+            totalTime: durations.reduce((sum, d) => sum + (d || 0), 0),
+            playlists: music.userPlaylists.persistentID().filter(id => id !== queueId).length
+        }});
+    "#);
+
+    let result = run_jxa_slow(&script)?;
+    serde_json::from_str(&result).context("보관함 통계 파싱 실패")
+}
+
+/// 이퀄라이저 프리셋 이름 목록 (Music.app 표시 순서)
+pub fn get_eq_presets() -> Result<Vec<String>> {
+    let script = format!(r#"
+        {ASCII_JSON_FN}
+        asciiJson(Application("Music").eqPresets.name());
+    "#);
+
+    let result = run_jxa(&script)?;
+    serde_json::from_str(&result).context("EQ 프리셋 목록 파싱 실패")
+}
+
+/// 이퀄라이저 프리셋 적용 (꺼져 있으면 함께 켬)
+pub fn set_eq_preset(name: &str) -> Result<()> {
+    let name_json = serde_json::to_string(name).context("EQ 프리셋 이름 직렬화 실패")?;
+    run_jxa(&format!(
+        "const music = Application('Music'); music.currentEQPreset = music.eqPresets.byName({}); music.eqEnabled = true;",
+        name_json
+    ))?;
+    Ok(())
+}
+
+/// 이퀄라이저 켜기/끄기
+pub fn set_eq_enabled(enabled: bool) -> Result<()> {
+    run_jxa(&format!("Application('Music').eqEnabled = {}", enabled))?;
+    Ok(())
+}
+
+/// persistentID로 플레이리스트 처음부터 재생
+pub fn play_playlist(id: &str) -> Result<()> {
+    let id_json = serde_json::to_string(id).context("플레이리스트 ID 직렬화 실패")?;
+    run_jxa(&format!(
+        "Application('Music').playlists.whose({{persistentID: {}}})[0].play()",
+        id_json
+    ))?;
+    Ok(())
+}
+
+/// persistentID로 찾은 플레이리스트의 트랙 목록 (플레이리스트 순서)
+pub fn get_playlist_tracks(id: &str) -> Result<Vec<SearchResult>> {
+    let id_json = serde_json::to_string(id).context("플레이리스트 ID 직렬화 실패")?;
+
+    let script = format!(r#"
+        {ASCII_JSON_FN}
+        let output = [];
+        try {{
+            const tracks = Application("Music").userPlaylists.whose({{persistentID: {id_json}}})[0].tracks();
+            output = tracks.map(t => ({{
+                name: t.name(),
+                artist: t.artist(),
+                album: t.album(),
+                year: t.year(),
+                duration: t.duration(),
+                bpm: t.bpm(),
+                id: t.persistentID()
+            }}));
+        }} catch(e) {{}}
+        asciiJson(output);
+    "#);
+
+    let result = run_jxa_slow(&script)?;
+    Ok(serde_json::from_str(&result).unwrap_or_default())
+}
+
+/// persistentID로 찾은 플레이리스트를 지정한 위치(0부터)의 트랙부터 재생 (이후 곡도 플레이리스트 순서대로 재생)
+pub fn play_playlist_from(id: &str, index: usize) -> Result<()> {
+    let id_json = serde_json::to_string(id).context("플레이리스트 ID 직렬화 실패")?;
+    run_jxa(&format!(
+        "Application('Music').userPlaylists.whose({{persistentID: {}}})[0].tracks[{}].play()",
+        id_json, index
+    ))?;
+    Ok(())
+}
+
+/// 보관함의 아티스트 목록 (중복 제거, 이름순)
+pub fn get_library_artists() -> Result<Vec<String>> {
+    let script = format!(r#"
+        {ASCII_JSON_FN}
+        let output = [];
+        try {{
+            const artists = Application("Music").libraryPlaylists[0].tracks.artist();
+            output = [...new Set(artists.filter(a => a))].sort((a, b) => a.localeCompare(b));
+        }} catch(e) {{}}
+        asciiJson(output);
+    "#);
+
+    let result = run_jxa_slow(&script)?;
+    serde_json::from_str(&result).context("아티스트 목록 파싱 실패")
+}
+
+/// 아티스트의 앨범 목록 (중복 제거, 이름순)
+pub fn get_artist_albums(artist: &str) -> Result<Vec<String>> {
+    let artist_json = serde_json::to_string(artist).context("아티스트 직렬화 실패")?;
+
+    let script = format!(r#"
+        {ASCII_JSON_FN}
+        let output = [];
+        try {{
+            const albums = Application("Music").libraryPlaylists[0].tracks.whose({{artist: {artist_json}}}).album();
+            output = [...new Set(albums.filter(a => a))].sort((a, b) => a.localeCompare(b));
+        }} catch(e) {{}}
+        asciiJson(output);
+    "#);
+
+    let result = run_jxa_slow(&script)?;
+    serde_json::from_str(&result).context("앨범 목록 파싱 실패")
+}
+
+/// 검색 결과 재생 큐로 사용하는 임시 플레이리스트 이름
+const QUEUE_PLAYLIST_NAME: &str = "Apple Music TUI Queue";
+
+/// 마지막으로 만든 임시 큐 플레이리스트 persistentID (이름이 같은 사용자 플레이리스트와 구분)
+static QUEUE_PLAYLIST_ID: Mutex<Option<String>> = Mutex::new(None);
+
+/// 큐 플레이리스트 ID 저장 파일 (다시 실행해도 지난 큐를 찾아 지울 수 있도록)
+fn queue_playlist_id_path() -> Option<PathBuf> {
+    Config::dir().map(|dir| dir.join("queue_playlist_id"))
+}
+
+/// 임시 큐 플레이리스트 persistentID (이번 실행에서 아직 만들지 않았으면 지난 실행에서 저장한 값)
+fn queue_playlist_id() -> Option<String> {
+    let mut id = QUEUE_PLAYLIST_ID.lock().ok()?;
+    if id.is_none() {
+        *id = queue_playlist_id_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .map(|content| content.trim().to_string())
+            .filter(|content| !content.is_empty());
+    }
+    id.clone()
+}
+
+/// 새로 만든 임시 큐 플레이리스트 persistentID 기억
+fn set_queue_playlist_id(id: &str) {
+    if let Ok(mut current) = QUEUE_PLAYLIST_ID.lock() {
+        *current = Some(id.to_string());
+    }
+    if let Some(path) = queue_playlist_id_path() {
+        let _ = std::fs::write(path, id);
+    }
+}
+
+/// "다음에 재생" 결과
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueueNext {
+    /// 현재 곡 다음에 추가함 (재생 중인 곡이 없었으면 바로 재생)
+    Queued,
+    /// 보관함에 없는 트랙
+    NotFound,
+    /// 임시 큐 플레이리스트 밖에서 재생 중 (Music.app 다음 재생 목록은 스크립트로 다룰 수 없음)
+    Unsupported,
+}
+
+/// 보관함 트랙을 현재 곡 바로 다음에 재생되도록 추가
+/// 임시 큐 플레이리스트를 재생 중일 때만 현재 곡 뒤에 끼워 넣을 수 있습니다.
+/// 다른 플레이리스트를 재생 중이면 재생을 건드리지 않고 `QueueNext::Unsupported`를 반환합니다.
+pub fn queue_next(id: &str) -> Result<QueueNext> {
+    let id_json = serde_json::to_string(id).context("트랙 ID 직렬화 실패")?;
+    let queue_json = serde_json::to_string(&queue_playlist_id()).context("큐 플레이리스트 ID 직렬화 실패")?;
+
+    let script = format!(r#"
+        const music = Application("Music");
+        const queueId = {queue_json};
+        let output = "not_found";
+        try {{
+            const library = music.libraryPlaylists[0];
+            const matches = library.tracks.whose({{persistentID: {id_json}}});
+            if (matches.length > 0) {{
+                const track = matches[0];
+                let currentId = null;
+                let playlistId = null;
+                try {{ currentId = music.currentTrack().persistentID(); }} catch(e) {{}}
+                try {{ playlistId = music.currentPlaylist().persistentID(); }} catch(e) {{}}
+
+                if (currentId === null) {{
+                    track.play();
+                    output = "queued";
+                }} else if (queueId !== null && playlistId === queueId) {{
+                    // 추가한 곡 뒤로 남은 곡들을 다시 붙이고 원래 항목은 뒤에서부터 삭제
+                    const queue = music.currentPlaylist();
+                    const ids = queue.tracks.persistentID();
+                    const start = ids.indexOf(currentId);
+                    music.duplicate(track, {{to: queue}});
+                    for (let i = start + 1; i < ids.length; i++) {{
+                        music.duplicate(queue.tracks[i], {{to: queue}});
+                    }}
+                    for (let i = ids.length - 1; i > start; i--) {{
+                        music.delete(queue.tracks[i]);
+                    }}
+                    output = "queued";
+                }} else {{
+                    output = "unsupported";
+                }}
+            }}
+        }} catch(e) {{}}
+        JSON.stringify(output);
+    "#);
+
+    let result = run_jxa_slow(&script)?;
+    serde_json::from_str(&result).context("큐 추가 결과 파싱 실패")
+}
+
+/// `play_tracks_as_queue` 스크립트 출력
+#[derive(Deserialize)]
+struct QueueOutcome {
+    /// 새로 만든 큐 플레이리스트 persistentID
+    id: String,
+    /// 큐에 추가하지 못한 트랙 수
+    failed: usize,
+}
+
+/// 라이브러리 트랙 목록을 임시 플레이리스트로 만들어 순서대로 재생
+/// 지난번에 만든 임시 플레이리스트는 persistentID로 찾아 지우므로 같은 이름의 사용자 플레이리스트는 건드리지 않습니다.
+/// 추가하지 못한 트랙이 있으면 재생하지 않고 에러를 반환합니다.
+pub fn play_tracks_as_queue(ids: &[String]) -> Result<()> {
+    let ids_json = serde_json::to_string(ids).context("트랙 ID 직렬화 실패")?;
+    let previous_json = serde_json::to_string(&queue_playlist_id()).context("큐 플레이리스트 ID 직렬화 실패")?;
+
+    let script = format!(r#"
+        const music = Application("Music");
+        const previousId = {previous_json};
+        if (previousId !== null) {{
+            const existing = music.userPlaylists.whose({{persistentID: previousId}});
+            for (let i = existing.length - 1; i >= 0; i--) {{
+                music.delete(existing[i]);
+            }}
+        }}
+
+        const queue = music.make({{new: "userPlaylist", withProperties: {{name: "{QUEUE_PLAYLIST_NAME}"}}}});
+        const library = music.libraryPlaylists[0];
+        const ids = {ids_json};
+        let failed = 0;
+
+        for (const id of ids) {{
+            try {{
+                music.duplicate(library.tracks.whose({{persistentID: id}})[0], {{to: queue}});
+            }} catch(e) {{
+                failed++;
+            }}
+        }}
+
+        if (failed === 0 && queue.tracks.length > 0) {{
+            queue.play();
+        }}
+        JSON.stringify({{id: queue.persistentID(), failed: failed}});
+    "#);
+
+    let result = run_jxa_slow(&script)?;
+    let outcome: QueueOutcome = serde_json::from_str(&result).context("큐 생성 결과 파싱 실패")?;
+    set_queue_playlist_id(&outcome.id);
+    if outcome.failed > 0 {
+        bail!("큐에 트랙 {}개 추가 실패", outcome.failed);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_itunes_response_rejects_html_page() {
+        let body = "<!DOCTYPE html>\n<html><head><title>Rate limited</title></head><body>Try again later</body></html>";
+        assert!(parse_itunes_response(body).is_err());
+    }
+
+    #[test]
+    fn extract_artwork_urls_unescapes_slashes() {
+        // iTunes API는 URL의 `/`를 `\/`로 이스케이프해서 보냄
+        let body = r#"{"resultCount":2,"results":[
+            {"artworkUrl100":"https:\/\/is1-ssl.mzstatic.com\/image\/thumb\/a.jpg\/100x100bb.jpg"},
+            {"collectionName":"No Artwork"}
+        ]}"#;
+        let json = parse_itunes_response(body).unwrap();
+        assert_eq!(
+            extract_artwork_urls(&json),
+            vec!["https://is1-ssl.mzstatic.com/image/thumb/a.jpg/100x100bb.jpg".to_string()]
+        );
+    }
+
+    #[test]
+    fn ascii_json_track_keeps_unusual_unicode() {
+        // asciiJson() 출력 형태: 결합 문자, RTL 문자, 서로게이트 쌍 이모지가 모두 \uXXXX로 이스케이프됨
+        let output = r#"{"id":"ABC","name":"Ame\u0301lie \u05e9\u05dc\u05d5\u05dd \ud83c\udfb5",
+            "artist":"\u0641\u064a\u0631\u0648\u0632","album":"","duration":200,"playerPosition":0,"state":"playing"}"#;
+        let raw: RawTrackInfo = serde_json::from_str(output).unwrap();
+        let track = TrackInfo::from(raw);
+        assert_eq!(track.name, "Ame\u{301}lie שלום 🎵");
+        assert_eq!(track.artist, "فيروز");
+    }
+}
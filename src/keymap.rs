@@ -0,0 +1,319 @@
+//! 키맵 모듈
+//! 기본 모드의 키 입력을 동작(`Action`)으로 매핑합니다.
+
+use crate::app::Action;
+use crate::config::KeyBinding;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+
+/// 내장 키맵 프리셋
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum KeyMapPreset {
+    /// 방향키 + h/j/k/l
+    #[default]
+    Default,
+    /// h/j/k/l만 (방향키 없음)
+    Vim,
+    /// Ctrl-b/f/p/n + 방향키
+    Emacs,
+    /// 방향키만 (h/j/k/l 없음)
+    ArrowsOnly,
+}
+
+impl KeyMapPreset {
+    /// 설정 값에서 프리셋 찾기
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(KeyMapPreset::Default),
+            "vim" => Some(KeyMapPreset::Vim),
+            "emacs" => Some(KeyMapPreset::Emacs),
+            "arrows-only" => Some(KeyMapPreset::ArrowsOnly),
+            _ => None,
+        }
+    }
+}
+
+/// 키 → 동작 매핑
+#[derive(Debug, Clone)]
+pub struct KeyMap {
+    bindings: HashMap<(KeyCode, KeyModifiers), Action>,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self::preset(KeyMapPreset::Default)
+    }
+}
+
+impl KeyMap {
+    /// 프리셋 키맵 생성
+    pub fn preset(preset: KeyMapPreset) -> Self {
+        let mut keymap = KeyMap { bindings: HashMap::new() };
+        let none = KeyModifiers::NONE;
+        let ctrl = KeyModifiers::CONTROL;
+
+        // 모든 프리셋 공통
+        keymap.bind(KeyCode::Char(' '), none, Action::PlayPause);
+        keymap.bind(KeyCode::Char('['), none, Action::PreviousChapter);
+        keymap.bind(KeyCode::Char(']'), none, Action::NextChapter);
+        keymap.bind(KeyCode::Char(','), none, Action::SeekBackward);
+        keymap.bind(KeyCode::Char('.'), none, Action::SeekForward);
+        keymap.bind(KeyCode::Char('<'), none, Action::SeekBackwardLong);
+        keymap.bind(KeyCode::Char('>'), none, Action::SeekForwardLong);
+        keymap.bind(KeyCode::Char('g'), none, Action::SeekPercentPrefix);
+        keymap.bind(KeyCode::Char('/'), none, Action::Search);
+        keymap.bind(KeyCode::Char('a'), none, Action::CycleArtwork);
+        keymap.bind(KeyCode::Char('t'), none, Action::ToggleTitleNowPlaying);
+        keymap.bind(KeyCode::Char('T'), none, Action::ToggleShowRemaining);
+        keymap.bind(KeyCode::Char('v'), none, Action::ToggleVolumeTarget);
+        keymap.bind(KeyCode::Char('d'), none, Action::Duck);
+        keymap.bind(KeyCode::Char('m'), none, Action::ToggleMute);
+        for stars in 0..=5u8 {
+            keymap.bind(KeyCode::Char((b'0' + stars) as char), none, Action::Rate(stars));
+        }
+        keymap.bind(KeyCode::Char('f'), none, Action::ToggleLoved);
+        keymap.bind(KeyCode::Char('F'), none, Action::ToggleFocus);
+        keymap.bind(KeyCode::Char('c'), none, Action::ToggleCompact);
+        keymap.bind(KeyCode::Char('w'), none, Action::ToggleSlideshow);
+        keymap.bind(KeyCode::Char('P'), none, Action::CycleProfile);
+        keymap.bind(KeyCode::Char('C'), none, Action::CycleTheme);
+        keymap.bind(KeyCode::Char('V'), none, Action::CycleVolumeStep);
+        keymap.bind(KeyCode::Char('z'), none, Action::PauseAfterCurrent);
+        keymap.bind(KeyCode::Char('I'), none, Action::ToggleAutoplay);
+        keymap.bind(KeyCode::Char('s'), none, Action::ToggleShuffle);
+        keymap.bind(KeyCode::Char('r'), none, Action::CycleRepeat);
+        keymap.bind(KeyCode::Char('A'), none, Action::QueueRestOfAlbum);
+        keymap.bind(KeyCode::Char('x'), none, Action::SkipAndRemember);
+        keymap.bind(KeyCode::Char('X'), none, Action::OpenSkipLog);
+        keymap.bind(KeyCode::Char('o'), none, Action::OpenAirPlay);
+        keymap.bind(KeyCode::Char('S'), none, Action::OpenSessionStats);
+        keymap.bind(KeyCode::Char('i'), none, Action::OpenLibraryStats);
+        keymap.bind(KeyCode::Char('L'), none, Action::OpenLyrics);
+        keymap.bind(KeyCode::Char('E'), none, Action::ExportHistory);
+        keymap.bind(KeyCode::Char('B'), none, Action::OpenBrowser);
+        keymap.bind(KeyCode::Char('u'), none, Action::OpenUpNext);
+        keymap.bind(KeyCode::Char('p'), none, Action::OpenPlaylists);
+        keymap.bind(KeyCode::Char('e'), none, Action::OpenEqualizer);
+        keymap.bind(KeyCode::Char(':'), none, Action::OpenCommand);
+        keymap.bind(KeyCode::Char('?'), none, Action::ToggleHelp);
+        keymap.bind(KeyCode::Char('R'), none, Action::RelaunchMusic);
+        keymap.bind(KeyCode::Char('l'), ctrl, Action::Refresh);
+        keymap.bind(KeyCode::F(5), none, Action::Refresh);
+        keymap.bind(KeyCode::Char('q'), none, Action::Quit);
+        keymap.bind(KeyCode::Esc, none, Action::Quit);
+
+        // 방향키
+        if preset != KeyMapPreset::Vim {
+            keymap.bind(KeyCode::Left, none, Action::PreviousTrack);
+            keymap.bind(KeyCode::Right, none, Action::NextTrack);
+            keymap.bind(KeyCode::Up, none, Action::VolumeUp);
+            keymap.bind(KeyCode::Down, none, Action::VolumeDown);
+            keymap.bind(KeyCode::Up, KeyModifiers::SHIFT, Action::VolumeUpFine);
+            keymap.bind(KeyCode::Down, KeyModifiers::SHIFT, Action::VolumeDownFine);
+        }
+
+        // h/j/k/l
+        if matches!(preset, KeyMapPreset::Default | KeyMapPreset::Vim) {
+            keymap.bind(KeyCode::Char('h'), none, Action::PreviousTrack);
+            keymap.bind(KeyCode::Char('l'), none, Action::NextTrack);
+            keymap.bind(KeyCode::Char('k'), none, Action::VolumeUp);
+            keymap.bind(KeyCode::Char('j'), none, Action::VolumeDown);
+            keymap.bind(KeyCode::Char('K'), none, Action::VolumeUpFine);
+            keymap.bind(KeyCode::Char('J'), none, Action::VolumeDownFine);
+        }
+
+        // Emacs 스타일 Ctrl 키
+        if preset == KeyMapPreset::Emacs {
+            keymap.bind(KeyCode::Char('b'), ctrl, Action::PreviousTrack);
+            keymap.bind(KeyCode::Char('f'), ctrl, Action::NextTrack);
+            keymap.bind(KeyCode::Char('p'), ctrl, Action::VolumeUp);
+            keymap.bind(KeyCode::Char('n'), ctrl, Action::VolumeDown);
+            keymap.bind(KeyCode::Char('s'), ctrl, Action::Search);
+        }
+
+        keymap
+    }
+
+    /// 키 바인딩 추가 (기존 바인딩 덮어쓰기)
+    pub fn bind(&mut self, code: KeyCode, modifiers: KeyModifiers, action: Action) {
+        self.bindings.insert(normalize(code, modifiers), action);
+    }
+
+    /// 키 입력에 매핑된 동작
+    pub fn get(&self, key: KeyEvent) -> Option<Action> {
+        self.bindings.get(&normalize(key.code, key.modifiers)).copied()
+    }
+
+    /// 동작에 매핑된 키 이름 목록 (도움말 표시용, 이름순)
+    pub fn keys_for(&self, action: Action) -> Vec<String> {
+        let mut keys: Vec<String> = self
+            .bindings
+            .iter()
+            .filter(|(_, bound)| **bound == action)
+            .map(|(&(code, modifiers), _)| key_label(code, modifiers))
+            .collect();
+        keys.sort();
+        keys
+    }
+
+    /// 설정 파일의 `[keys]` 바인딩 추가 (잘못된 항목은 건너뛰고 설명 목록 반환)
+    pub fn apply_overrides(&mut self, keys: &HashMap<String, KeyBinding>) -> Vec<String> {
+        let mut errors = Vec::new();
+        // 같은 키를 여러 동작에 지정했을 때 결과가 실행마다 달라지지 않도록 이름순으로 적용
+        let mut names: Vec<&String> = keys.keys().collect();
+        names.sort();
+
+        for name in names {
+            let Some(action) = action_from_name(name) else {
+                errors.push(format!("unknown action '{}'", name));
+                continue;
+            };
+            for key in keys[name].keys() {
+                match parse_key(key) {
+                    Some((code, modifiers)) => self.bind(code, modifiers, action),
+                    None => errors.push(format!("invalid key '{}' for {}", key, name)),
+                }
+            }
+        }
+        errors
+    }
+}
+
+/// 설정 파일에서 쓰는 동작 이름
+pub fn action_from_name(name: &str) -> Option<Action> {
+    let action = match name {
+        "play_pause" => Action::PlayPause,
+        "prev" | "previous_track" => Action::PreviousTrack,
+        "next" | "next_track" => Action::NextTrack,
+        "previous_chapter" => Action::PreviousChapter,
+        "next_chapter" => Action::NextChapter,
+        "seek_backward" => Action::SeekBackward,
+        "seek_forward" => Action::SeekForward,
+        "seek_backward_long" => Action::SeekBackwardLong,
+        "seek_forward_long" => Action::SeekForwardLong,
+        "seek_percent" => Action::SeekPercentPrefix,
+        "volume_up" => Action::VolumeUp,
+        "volume_down" => Action::VolumeDown,
+        "volume_up_fine" => Action::VolumeUpFine,
+        "volume_down_fine" => Action::VolumeDownFine,
+        "search" => Action::Search,
+        "cycle_artwork" => Action::CycleArtwork,
+        "toggle_title_now_playing" => Action::ToggleTitleNowPlaying,
+        "toggle_show_remaining" => Action::ToggleShowRemaining,
+        "toggle_volume_target" => Action::ToggleVolumeTarget,
+        "duck" => Action::Duck,
+        "mute" => Action::ToggleMute,
+        "clear_rating" => Action::Rate(0),
+        "rate_1" => Action::Rate(1),
+        "rate_2" => Action::Rate(2),
+        "rate_3" => Action::Rate(3),
+        "rate_4" => Action::Rate(4),
+        "rate_5" => Action::Rate(5),
+        "toggle_loved" => Action::ToggleLoved,
+        "toggle_focus" => Action::ToggleFocus,
+        "toggle_compact" => Action::ToggleCompact,
+        "toggle_slideshow" => Action::ToggleSlideshow,
+        "cycle_profile" => Action::CycleProfile,
+        "cycle_theme" => Action::CycleTheme,
+        "cycle_volume_step" => Action::CycleVolumeStep,
+        "pause_after_current" => Action::PauseAfterCurrent,
+        "toggle_autoplay" => Action::ToggleAutoplay,
+        "toggle_shuffle" => Action::ToggleShuffle,
+        "cycle_repeat" => Action::CycleRepeat,
+        "queue_rest_of_album" => Action::QueueRestOfAlbum,
+        "skip_and_remember" => Action::SkipAndRemember,
+        "skip_log" => Action::OpenSkipLog,
+        "airplay" => Action::OpenAirPlay,
+        "session_stats" => Action::OpenSessionStats,
+        "library_stats" => Action::OpenLibraryStats,
+        "lyrics" => Action::OpenLyrics,
+        "browser" => Action::OpenBrowser,
+        "up_next" => Action::OpenUpNext,
+        "playlists" => Action::OpenPlaylists,
+        "equalizer" => Action::OpenEqualizer,
+        "command" => Action::OpenCommand,
+        "export_history" => Action::ExportHistory,
+        "help" => Action::ToggleHelp,
+        "relaunch_music" => Action::RelaunchMusic,
+        "refresh" => Action::Refresh,
+        "quit" => Action::Quit,
+        _ => return None,
+    };
+    Some(action)
+}
+
+/// 키 이름 파싱 (예: "n", "space", "enter", "f5", "ctrl-n", "alt-left")
+fn parse_key(key: &str) -> Option<(KeyCode, KeyModifiers)> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut rest = key;
+    // 한 글자 키 "-"는 수정자 구분자가 아님
+    while rest.len() > 1
+        && let Some((prefix, remaining)) = rest.split_once('-')
+    {
+        modifiers |= match prefix.to_lowercase().as_str() {
+            "ctrl" => KeyModifiers::CONTROL,
+            "alt" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            _ => return None,
+        };
+        rest = remaining;
+    }
+
+    let mut chars = rest.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) => KeyCode::Char(c),
+        _ => match rest.to_lowercase().as_str() {
+            "space" => KeyCode::Char(' '),
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backspace" => KeyCode::Backspace,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            name => KeyCode::F(name.strip_prefix('f')?.parse().ok().filter(|n| (1..=12).contains(n))?),
+        },
+    };
+    Some((code, modifiers))
+}
+
+/// 화면 표시용 키 이름 (예: "Space", "Ctrl-l", "←")
+pub fn key_label(code: KeyCode, modifiers: KeyModifiers) -> String {
+    let name = match code {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::PageUp => "PgUp".to_string(),
+        KeyCode::PageDown => "PgDn".to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        other => format!("{:?}", other),
+    };
+
+    let mut label = String::new();
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        label.push_str("Ctrl-");
+    }
+    if modifiers.contains(KeyModifiers::ALT) {
+        label.push_str("Alt-");
+    }
+    if modifiers.contains(KeyModifiers::SHIFT) {
+        label.push_str("Shift-");
+    }
+    label + &name
+}
+
+/// 문자 키는 대소문자로 Shift가 구분되므로 SHIFT 수정자를 무시
+fn normalize(code: KeyCode, modifiers: KeyModifiers) -> (KeyCode, KeyModifiers) {
+    match code {
+        KeyCode::Char(_) => (code, modifiers - KeyModifiers::SHIFT),
+        _ => (code, modifiers),
+    }
+}
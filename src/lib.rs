@@ -0,0 +1,41 @@
+//! Apple Music TUI 라이브러리
+//! Music.app 제어 계층(`jxa`)과 Now Playing 텍스트 위젯을 다른 Rust 앱에서도
+//! 재사용할 수 있도록 공개합니다.
+
+pub mod app;
+pub(crate) mod artwork;
+pub(crate) mod browser;
+pub mod cli;
+pub mod command;
+pub mod config;
+pub(crate) mod discord;
+pub mod events;
+pub mod fifo;
+pub mod history;
+pub mod jxa;
+pub(crate) mod keymap;
+pub(crate) mod lyrics;
+pub(crate) mod peaks;
+pub mod poller;
+pub(crate) mod search;
+pub(crate) mod skips;
+pub(crate) mod state;
+pub(crate) mod stats;
+pub(crate) mod theme;
+pub(crate) mod track_loader;
+pub mod ui;
+
+pub use jxa::{PlayerState, TrackInfo};
+
+/// 현재 재생 상태를 두 줄 요약 문자열로 반환
+/// (`▶ 아티스트 - 제목` + 텍스트 진행 바/시간/볼륨)
+/// tmux 등에서 주기적으로 호출하므로 Music.app이 꺼져 있으면 다시 실행하지 않고 안내 한 줄만 반환합니다.
+pub fn get_now_playing_string() -> anyhow::Result<String> {
+    let status = jxa::get_player_status()?;
+    if !status.running {
+        return Ok("Music not running".to_string());
+    }
+    let track = status.track.unwrap_or_default();
+    let volume = status.volume.map_or(0, |volume| volume.app_volume);
+    Ok(ui::status_text(&track, volume))
+}
@@ -0,0 +1,87 @@
+//! 가사 모듈
+//! Music.app 가사 텍스트를 줄 단위로 나누고, LRC 형식(`[mm:ss.xx]가사`) 타임스탬프가 있으면
+//! 재생 위치에 맞는 현재 줄을 계산합니다.
+
+/// 가사 한 줄
+#[derive(Debug, Clone, PartialEq)]
+pub struct LyricLine {
+    /// 시작 시각 (초, 타임스탬프가 없으면 None)
+    pub time: Option<f64>,
+    pub text: String,
+}
+
+/// 트랙 가사
+#[derive(Debug, Clone, Default)]
+pub struct Lyrics {
+    pub lines: Vec<LyricLine>,
+}
+
+impl Lyrics {
+    /// 가사 텍스트 파싱
+    /// 한 줄에 타임스탬프가 여러 개면 (`[00:12.00][01:30.00]후렴`) 각각 별도 줄로 만들고 시간순 정렬
+    pub fn parse(text: &str) -> Self {
+        let mut lines = Vec::new();
+        for raw in text.lines() {
+            let (times, rest) = parse_timestamps(raw.trim());
+            if times.is_empty() {
+                // `[ar:Artist]` 같은 LRC 메타데이터 태그는 건너뜀
+                if !is_lrc_tag(raw.trim()) {
+                    lines.push(LyricLine { time: None, text: raw.trim_end().to_string() });
+                }
+            } else {
+                lines.extend(times.into_iter().map(|time| LyricLine { time: Some(time), text: rest.to_string() }));
+            }
+        }
+
+        let mut lyrics = Lyrics { lines };
+        if lyrics.is_synced() {
+            lyrics.lines.retain(|line| line.time.is_some());
+            lyrics.lines.sort_by(|a, b| a.time.unwrap_or(0.0).total_cmp(&b.time.unwrap_or(0.0)));
+        }
+        lyrics
+    }
+
+    /// 타임스탬프가 있는 가사인지 여부
+    pub fn is_synced(&self) -> bool {
+        self.lines.iter().any(|line| line.time.is_some())
+    }
+
+    /// 재생 위치에 해당하는 현재 줄 인덱스 (동기화 가사가 아니거나 첫 줄 전이면 None)
+    pub fn active_line(&self, position: f64) -> Option<usize> {
+        if !self.is_synced() {
+            return None;
+        }
+        self.lines
+            .iter()
+            .rposition(|line| line.time.is_some_and(|time| time <= position))
+    }
+}
+
+/// 줄 앞의 `[mm:ss.xx]` 타임스탬프들과 나머지 텍스트 분리
+fn parse_timestamps(line: &str) -> (Vec<f64>, &str) {
+    let mut times = Vec::new();
+    let mut rest = line;
+    while let Some(after) = rest.strip_prefix('[')
+        && let Some((tag, remaining)) = after.split_once(']')
+        && let Some(time) = parse_time(tag)
+    {
+        times.push(time);
+        rest = remaining;
+    }
+    (times, rest.trim())
+}
+
+/// `mm:ss`, `mm:ss.xx` 형식을 초로 변환
+fn parse_time(tag: &str) -> Option<f64> {
+    let (mins, secs) = tag.split_once(':')?;
+    let mins: u32 = mins.trim().parse().ok()?;
+    let secs: f64 = secs.trim().parse().ok()?;
+    (secs < 60.0).then_some(mins as f64 * 60.0 + secs)
+}
+
+/// `[ar:...]`, `[ti:...]` 등 LRC 메타데이터 태그 줄인지 여부
+fn is_lrc_tag(line: &str) -> bool {
+    line.starts_with('[')
+        && line.ends_with(']')
+        && line[1..].split_once(':').is_some_and(|(key, _)| key.chars().all(|c| c.is_ascii_alphabetic()))
+}
@@ -1,24 +1,45 @@
 //! Apple Music TUI Remote
 //! macOS Music.app을 터미널에서 제어하는 TUI 앱
 
-mod app;
-mod events;
-mod jxa;
-mod ui;
-
 use anyhow::Result;
-use app::App;
+use apple_music_tui::app::App;
+use apple_music_tui::cli::Args;
+use apple_music_tui::config::Config;
+use apple_music_tui::command::Command;
+use apple_music_tui::poller::{self, PlayerSnapshot};
+use apple_music_tui::{events, fifo, history, jxa, ui};
 use crossterm::{
-    event::{self, Event, KeyEventKind},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::prelude::*;
 use std::io::stdout;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
+use tokio::sync::mpsc::UnboundedReceiver;
+
+/// 키 입력 대기 시간 (폴링 결과도 이 간격으로 화면에 반영)
+const EVENT_POLL_TIMEOUT: Duration = Duration::from_millis(100);
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let args = Args::parse()?;
+
+    // --status: TUI 없이 상태만 출력하고 종료
+    if args.status {
+        return print_status();
+    }
+
+    // --export-history: 누적 재생 기록을 내보내고 종료
+    if let Some(path) = &args.export_history {
+        let entries = history::load();
+        history::export(&entries, path, history::ExportFormat::from_path(path))?;
+        println!("Exported {} tracks to {}", entries.len(), path.display());
+        return Ok(());
+    }
+
     // 터미널 초기화
     enable_raw_mode()?;
     let mut stdout = stdout();
@@ -29,48 +50,135 @@ async fn main() -> Result<()> {
     // Music.app이 실행되지 않았으면 자동 실행
     let _ = jxa::ensure_music_ready();
 
+    // 설정 로드 (실패 시 기본값 사용), --profile이 있으면 설정의 profile보다 우선
+    let config = Config::load().map(|mut config| {
+        if args.profile.is_some() {
+            config.profile = args.profile.clone();
+        }
+        config
+    });
+
     // 앱 상태 초기화
-    let mut app = App::new();
-    
+    let mut app = match config {
+        Ok(config) => App::new(config),
+        Err(e) => {
+            let mut app = App::new(Config::default());
+            app.set_status(format!("Config error: {:#}", e));
+            app
+        }
+    };
+
+    // --browse: 라이브러리 브라우저로 시작
+    if args.browse {
+        app.open_browser();
+    }
+
+    // 목록 클릭/휠 지원
+    if app.config.mouse {
+        execute!(terminal.backend_mut(), EnableMouseCapture)?;
+    }
+
+    // 외부 스크립트용 FIFO 명령 인터페이스
+    let mut commands = None;
+    if let Some(path) = app.config.command_fifo.clone() {
+        match fifo::spawn_reader(&fifo::expand_path(&path)) {
+            Ok(rx) => commands = Some(rx),
+            Err(e) => app.set_status(format!("FIFO error: {:#}", e)),
+        }
+    }
+
     // 초기 상태 로드
     app.update();
 
+    // --nowplaying-file: 현재 곡을 파일로 내보내기 (이후 곡이 바뀔 때마다 갱신)
+    if let Some(path) = args.nowplaying_file.clone() {
+        app.set_nowplaying_file(path);
+    }
+
+    // 이후 상태는 백그라운드에서 폴링
+    let poll_interval = Arc::new(AtomicU64::new(app.config.poll_interval_ms.max(100)));
+    let snapshots = poller::spawn(poll_interval.clone());
+
     // 메인 루프
-    let result = run_app(&mut terminal, &mut app).await;
+    let result = run_app(&mut terminal, &mut app, commands, snapshots, poll_interval).await;
+
+    // 다음 실행을 위해 화면 상태 저장 (실패해도 종료는 계속)
+    let _ = app.ui_state().save();
 
     // 터미널 복원
     disable_raw_mode()?;
+    if app.config.mouse {
+        execute!(terminal.backend_mut(), DisableMouseCapture)?;
+    }
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
     terminal.show_cursor()?;
 
+    // 아트워크 임시 파일 정리
+    app.remove_artwork_files();
+    app.clear_artwork_cache();
+
     result
 }
 
-async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()> {
-    let tick_rate = Duration::from_secs(1);
-    let mut last_tick = std::time::Instant::now();
+/// 현재 재생 상태를 두 줄 요약으로 출력 (tmux status-right 등에서 사용)
+fn print_status() -> Result<()> {
+    println!("{}", apple_music_tui::get_now_playing_string()?);
+    Ok(())
+}
 
+async fn run_app<B: Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+    mut commands: Option<UnboundedReceiver<Command>>,
+    mut snapshots: UnboundedReceiver<PlayerSnapshot>,
+    poll_interval: Arc<AtomicU64>,
+) -> Result<()> {
     while app.running {
+        // 프로필 전환으로 바뀔 수 있으므로 매번 폴링 작업에 전달
+        poll_interval.store(app.config.poll_interval_ms.max(100), Ordering::Relaxed);
+
         // UI 렌더링
         terminal.draw(|frame| ui::render(frame, app))?;
+        app.mark_picker_ready();
 
         // 이벤트 폴링 (100ms timeout)
-        let timeout = tick_rate
-            .checked_sub(last_tick.elapsed())
-            .unwrap_or_else(|| Duration::from_millis(100));
-
-        if event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
-                    events::handle_key_event(app, key);
+        if event::poll(EVENT_POLL_TIMEOUT)? {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => events::handle_key_event(app, key),
+                Event::Mouse(mouse) => events::handle_mouse_event(app, mouse),
+                // 크기가 바뀌면 다음 루프를 기다리지 않고 바로 다시 그림
+                // (이전 크기로 그린 아트워크 이미지가 남지 않도록 화면을 지운 뒤 새 영역에 맞춰 다시 인코딩)
+                Event::Resize(..) => {
+                    terminal.clear()?;
+                    terminal.draw(|frame| ui::render(frame, app))?;
                 }
+                _ => {}
+            }
+        }
+
+        // FIFO로 들어온 명령 처리
+        if let Some(rx) = commands.as_mut() {
+            while let Ok(command) = rx.try_recv() {
+                app.run_command(command);
             }
         }
 
-        // 1초마다 상태 업데이트
-        if last_tick.elapsed() >= tick_rate {
-            app.update();
-            last_tick = std::time::Instant::now();
+        // 입력을 멈춘 검색어 자동 검색, 백그라운드에서 끝난 검색 결과 반영
+        app.check_search_debounce();
+        app.receive_search_results();
+
+        // 긴 제목 스크롤, 아트워크 대신 그리는 막대 애니메이션
+        app.tick_marquee();
+        app.tick_spectrum();
+
+        // 백그라운드에서 끝난 아트워크, 보관함 통계, 트랙 부가 정보 반영
+        app.receive_artwork();
+        app.receive_library_stats();
+        app.receive_track_data();
+
+        // 백그라운드 폴링 결과 반영
+        while let Ok(snapshot) = snapshots.try_recv() {
+            app.apply_snapshot(snapshot);
         }
     }
 
@@ -0,0 +1,48 @@
+//! 음량 파형 모듈
+//! 로컬 파일 트랙을 `ffmpeg`로 디코딩해 구간별 최대 음량(피크)을 계산합니다.
+//! 진행 바 배경에 곡 전체의 음량 변화를 표시하는 데 사용합니다.
+
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// 디코딩 샘플레이트 (피크 계산용이라 낮게 설정)
+const SAMPLE_RATE: u32 = 8_000;
+
+/// 파일을 `bars`개 구간으로 나눠 구간별 피크 계산 (0.0-1.0, 가장 큰 피크 기준 정규화)
+pub fn compute(path: &Path, bars: usize) -> Result<Vec<f32>> {
+    let output = Command::new("ffmpeg")
+        .args(["-v", "error", "-i"])
+        .arg(path)
+        .args(["-ac", "1", "-ar", &SAMPLE_RATE.to_string(), "-f", "s16le", "-"])
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .context("ffmpeg 실행 실패")?;
+    if !output.status.success() {
+        bail!("오디오 디코딩 실패: {}", path.display());
+    }
+
+    let samples: Vec<i16> = output
+        .stdout
+        .chunks_exact(2)
+        .map(|bytes| i16::from_le_bytes([bytes[0], bytes[1]]))
+        .collect();
+    if samples.is_empty() || bars == 0 {
+        bail!("오디오 샘플 없음: {}", path.display());
+    }
+
+    let peaks: Vec<f32> = (0..bars)
+        .map(|bar| {
+            let start = bar * samples.len() / bars;
+            let end = ((bar + 1) * samples.len() / bars).max(start + 1).min(samples.len());
+            samples[start..end].iter().map(|s| s.unsigned_abs()).max().unwrap_or(0) as f32
+        })
+        .collect();
+
+    let max = peaks.iter().cloned().fold(0.0, f32::max);
+    if max <= 0.0 {
+        return Ok(vec![0.0; bars]);
+    }
+    Ok(peaks.into_iter().map(|peak| peak / max).collect())
+}
@@ -0,0 +1,59 @@
+//! 백그라운드 상태 폴링 모듈
+//! osascript 호출은 수십~수백 ms씩 걸리므로 UI 루프 대신 별도 작업에서 주기적으로
+//! 플레이어 상태를 읽어 채널로 보냅니다.
+
+use crate::jxa::{self, EqState, JxaTimeout, RepeatMode, TrackInfo, VolumeState};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+/// 한 번의 폴링 결과 (읽지 못한 항목은 None)
+#[derive(Debug, Clone, Default)]
+pub struct PlayerSnapshot {
+    pub track: Option<TrackInfo>,
+    pub shuffle: Option<bool>,
+    pub repeat: Option<RepeatMode>,
+    pub volume: Option<VolumeState>,
+    pub eq: Option<EqState>,
+    /// Music.app이 제한 시간 안에 응답하지 않음
+    pub timed_out: bool,
+}
+
+impl PlayerSnapshot {
+    /// 현재 플레이어 상태 읽기 (블로킹, osascript 한 번)
+    /// Music.app이 종료된 상태면 다시 실행되지 않도록 아무것도 읽지 않습니다.
+    pub fn fetch() -> Self {
+        match jxa::get_player_status() {
+            Ok(status) => PlayerSnapshot {
+                track: status.track,
+                shuffle: status.shuffle,
+                repeat: status.repeat,
+                volume: status.volume,
+                eq: status.eq,
+                timed_out: false,
+            },
+            Err(e) => PlayerSnapshot { timed_out: e.is::<JxaTimeout>(), ..PlayerSnapshot::default() },
+        }
+    }
+}
+
+/// 폴링 작업 시작
+/// `interval_ms`는 프로필 전환으로 바뀔 수 있으므로 매 주기마다 다시 읽습니다.
+pub fn spawn(interval_ms: Arc<AtomicU64>) -> UnboundedReceiver<PlayerSnapshot> {
+    let (tx, rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        loop {
+            let interval = Duration::from_millis(interval_ms.load(Ordering::Relaxed));
+            tokio::time::sleep(interval).await;
+
+            let Ok(snapshot) = tokio::task::spawn_blocking(PlayerSnapshot::fetch).await else {
+                continue;
+            };
+            if tx.send(snapshot).is_err() {
+                return;
+            }
+        }
+    });
+    rx
+}
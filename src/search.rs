@@ -0,0 +1,129 @@
+//! 백그라운드 검색 모듈
+//! 보관함 검색(osascript)이나 Apple Music 검색(HTTP)은 몇 초씩 걸릴 수 있으므로
+//! 별도 스레드에서 실행하고 결과를 채널로 App에 돌려줍니다.
+//! 요청마다 번호를 붙여 취소했거나 더 새로운 검색이 시작된 뒤 도착한 결과는 버릴 수 있게 합니다.
+
+use crate::app::SearchMode;
+use crate::jxa::{self, SearchResult};
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+/// 화면에 보여줄 보관함 검색 결과 수
+const LIBRARY_RESULTS: usize = 20;
+/// 퍼지 순위를 매기기 전에 가져올 보관함 후보 수
+const FUZZY_CANDIDATES: usize = 100;
+
+/// 끝난 검색 결과
+pub struct SearchOutcome {
+    /// 요청 번호 (`Searcher::start` 반환값)
+    pub id: u64,
+    /// 검색한 검색어
+    pub query: String,
+    /// 검색 결과 (모두 실패하면 None)
+    pub results: Option<Vec<SearchResult>>,
+    /// 사용자에게 보여줄 실패/부분 실패 안내
+    pub status: Option<&'static str>,
+}
+
+/// 검색 실행기 (요청마다 작업 스레드 하나)
+pub struct Searcher {
+    tx: Sender<SearchOutcome>,
+    rx: Receiver<SearchOutcome>,
+    next_id: u64,
+}
+
+impl Default for Searcher {
+    fn default() -> Self {
+        let (tx, rx) = mpsc::channel();
+        Searcher { tx, rx, next_id: 0 }
+    }
+}
+
+impl Searcher {
+    /// 백그라운드 검색 시작 (요청 번호 반환, `playlist`는 검색 범위 플레이리스트 ID,
+    /// `fuzzy`면 보관함 결과를 퍼지 점수순으로 정렬)
+    pub fn start(&mut self, mode: SearchMode, query: &str, playlist: Option<&str>, fuzzy: bool) -> u64 {
+        self.next_id += 1;
+        let id = self.next_id;
+        let query = query.to_string();
+        let playlist = playlist.map(str::to_string);
+        let tx = self.tx.clone();
+        std::thread::spawn(move || {
+            let (results, status) = run(mode, &query, playlist.as_deref(), fuzzy);
+            let _ = tx.send(SearchOutcome { id, query, results, status });
+        });
+        id
+    }
+
+    /// 끝난 검색 결과 하나 가져오기 (없으면 None)
+    pub fn try_recv(&self) -> Option<SearchOutcome> {
+        self.rx.try_recv().ok()
+    }
+}
+
+/// 검색 소스에 따라 검색 (실패 원인을 알 수 있도록 빈 결과와 구분해서 안내)
+fn run(mode: SearchMode, query: &str, playlist: Option<&str>, fuzzy: bool) -> (Option<Vec<SearchResult>>, Option<&'static str>) {
+    match mode {
+        SearchMode::Library => match search_library(query, playlist, fuzzy) {
+            Ok(results) => (Some(results), None),
+            Err(_) => (None, Some("Library search failed")),
+        },
+        SearchMode::AppleMusic => match jxa::search_apple_music(query) {
+            Ok(results) => (Some(results), None),
+            Err(e) if e.is::<jxa::HttpTimeout>() => (None, Some("Apple Music search timed out")),
+            Err(_) => (None, Some("Apple Music search unavailable")),
+        },
+        SearchMode::Combined => run_combined(query, playlist, fuzzy),
+    }
+}
+
+/// 보관함과 Apple Music을 함께 검색 (보관함 결과 먼저)
+/// 한쪽만 실패하면 나머지 결과를 보여주고 안내 메시지로 알림
+fn run_combined(query: &str, playlist: Option<&str>, fuzzy: bool) -> (Option<Vec<SearchResult>>, Option<&'static str>) {
+    let library = search_library(query, playlist, fuzzy);
+    let catalog = jxa::search_apple_music(query);
+    match (library, catalog) {
+        (Ok(mut library), Ok(catalog)) => {
+            library.extend(catalog);
+            (Some(library), None)
+        }
+        (Ok(library), Err(e)) => (
+            Some(library),
+            Some(if e.is::<jxa::HttpTimeout>() {
+                "Apple Music search timed out, showing library results"
+            } else {
+                "Apple Music search unavailable, showing library results"
+            }),
+        ),
+        (Err(_), Ok(catalog)) => (Some(catalog), Some("Library search failed, showing Apple Music results")),
+        (Err(_), Err(_)) => (None, Some("Library and Apple Music search failed")),
+    }
+}
+
+/// 보관함 검색 (`fuzzy`면 후보를 넓게 가져와 점수순으로 정렬한 뒤 상위 결과만 남김)
+fn search_library(query: &str, playlist: Option<&str>, fuzzy: bool) -> anyhow::Result<Vec<SearchResult>> {
+    if !fuzzy {
+        return jxa::search_library(query, playlist, false, LIBRARY_RESULTS);
+    }
+    let candidates = jxa::search_library(query, playlist, true, FUZZY_CANDIDATES)?;
+    Ok(rank_fuzzy(query, candidates, LIBRARY_RESULTS))
+}
+
+/// 검색어 단어마다 제목/아티스트/앨범과 퍼지 매칭한 점수 합으로 정렬
+/// 어느 단어와도 맞지 않는 결과는 버리고 최대 `limit`개만 남깁니다.
+fn rank_fuzzy(query: &str, results: Vec<SearchResult>, limit: usize) -> Vec<SearchResult> {
+    let matcher = SkimMatcherV2::default().ignore_case();
+    let words: Vec<&str> = query.split_whitespace().collect();
+    let mut scored: Vec<(i64, SearchResult)> = results
+        .into_iter()
+        .filter_map(|result| {
+            let haystack = format!("{} {} {}", result.name, result.artist, result.album);
+            let score: i64 = words.iter().filter_map(|word| matcher.fuzzy_match(&haystack, word)).sum();
+            (score > 0).then_some((score, result))
+        })
+        .collect();
+    // 점수가 같으면 Music.app 검색 순서 유지
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored.into_iter().take(limit).map(|(_, result)| result).collect()
+}
@@ -0,0 +1,73 @@
+//! 스킵 기록 모듈
+//! "건너뛰고 기억하기"로 넘긴 트랙을 `skips.json`에 누적 기록합니다.
+
+use crate::config::Config;
+use crate::jxa::TrackInfo;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// 스킵된 트랙 기록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkipEntry {
+    /// 트랙 persistentID
+    pub id: String,
+    pub name: String,
+    pub artist: String,
+    /// 누적 스킵 횟수
+    pub count: u32,
+}
+
+/// 스킵 기록 목록
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SkipLog {
+    pub entries: Vec<SkipEntry>,
+}
+
+impl SkipLog {
+    /// 스킵 기록 파일 경로
+    fn path() -> Option<PathBuf> {
+        Config::dir().map(|dir| dir.join("skips.json"))
+    }
+
+    /// 스킵 기록 로드 (파일이 없거나 읽을 수 없으면 빈 기록)
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// 스킵 기록 저장
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path().context("HOME 경로를 찾을 수 없음")?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).context("설정 디렉토리 생성 실패")?;
+        }
+        let content = serde_json::to_string_pretty(self).context("스킵 기록 직렬화 실패")?;
+        std::fs::write(&path, content).context("스킵 기록 저장 실패")
+    }
+
+    /// 트랙 스킵 기록 (누적 횟수 반환)
+    pub fn record(&mut self, track: &TrackInfo) -> u32 {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == track.id) {
+            entry.count += 1;
+            return entry.count;
+        }
+
+        self.entries.push(SkipEntry {
+            id: track.id.clone(),
+            name: track.name.clone(),
+            artist: track.artist.clone(),
+            count: 1,
+        });
+        1
+    }
+
+    /// 스킵 횟수가 많은 순으로 정렬된 기록
+    pub fn most_skipped(&self) -> Vec<&SkipEntry> {
+        let mut entries: Vec<&SkipEntry> = self.entries.iter().collect();
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.count));
+        entries
+    }
+}
@@ -0,0 +1,54 @@
+//! UI 상태 저장 모듈
+//! 레이아웃, 검색 소스 등 키로 전환하는 화면 상태를 종료 시 `state.json`에 저장하고
+//! 다음 실행 때 복원합니다.
+
+use crate::app::{LayoutMode, SearchMode};
+use crate::config::Config;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// 저장되는 UI 상태 (값이 없으면 설정 파일/기본값 사용)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiState {
+    /// 화면 레이아웃 (`F`)
+    pub layout: Option<LayoutMode>,
+    /// 포커스 모드 아트워크 슬라이드쇼 (`w`)
+    pub slideshow: Option<bool>,
+    /// 마지막으로 사용한 검색 소스 (`Tab`)
+    pub search_mode: Option<SearchMode>,
+    /// 타이틀 줄 현재 곡 표시 (`t`)
+    pub title_now_playing: Option<bool>,
+    /// 남은 시간 표시 (`T`)
+    pub show_remaining: Option<bool>,
+    /// 실행 중 바꾼 내장 테마 (`C`, 설정 파일 값과 같으면 저장하지 않음)
+    pub theme: Option<String>,
+    /// 실행 중 바꾼 볼륨 조절 단위 (`V`, 설정 파일 값과 같으면 저장하지 않음)
+    pub volume_step: Option<u8>,
+}
+
+impl UiState {
+    /// 상태 파일 경로
+    fn path() -> Option<PathBuf> {
+        Config::dir().map(|dir| dir.join("state.json"))
+    }
+
+    /// 상태 로드 (파일이 없거나 읽을 수 없으면 빈 상태)
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// 상태 저장
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path().context("HOME 경로를 찾을 수 없음")?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).context("설정 디렉토리 생성 실패")?;
+        }
+        let content = serde_json::to_string_pretty(self).context("UI 상태 직렬화 실패")?;
+        std::fs::write(&path, content).context("UI 상태 저장 실패")
+    }
+}
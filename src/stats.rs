@@ -0,0 +1,64 @@
+//! 세션 통계 모듈
+//! 현재 TUI 세션 동안의 재생 통계를 메모리에만 누적합니다 (저장하지 않음).
+
+use crate::history::HistoryEntry;
+use crate::jxa::{PlayerState, TrackInfo};
+use std::collections::HashMap;
+
+/// 곡이 끝나기 전에 넘어갔다고 판단하는 남은 시간 (초)
+const SKIP_REMAINING_THRESHOLD: f64 = 5.0;
+/// 한 번의 폴링 사이에 더할 수 있는 최대 재생 시간 (초, 탐색으로 인한 점프 제외용)
+const MAX_LISTENING_STEP: f64 = 5.0;
+
+/// 현재 세션 재생 통계
+#[derive(Debug, Clone, Default)]
+pub struct SessionStats {
+    /// 재생된 트랙 수
+    pub tracks_played: u32,
+    /// 끝까지 듣지 않고 넘긴 트랙 수
+    pub skips: u32,
+    /// 총 청취 시간 (초)
+    pub listening_time: f64,
+    /// 아티스트별 재생 횟수
+    artist_plays: HashMap<String, u32>,
+    /// 이번 세션에 재생한 곡 (재생 순서)
+    pub history: Vec<HistoryEntry>,
+}
+
+impl SessionStats {
+    /// 이전/현재 폴링 결과를 비교해 통계 갱신
+    pub fn observe(&mut self, previous: &TrackInfo, current: &TrackInfo) {
+        if current.id != previous.id {
+            // 이전 곡이 끝나기 전에 바뀌었으면 스킵으로 계산
+            if !previous.id.is_empty()
+                && previous.duration > 0.0
+                && previous.duration - previous.player_position > SKIP_REMAINING_THRESHOLD
+            {
+                self.skips += 1;
+            }
+
+            if !current.id.is_empty() {
+                self.tracks_played += 1;
+                *self.artist_plays.entry(current.artist.clone()).or_default() += 1;
+                self.history.push(HistoryEntry::now(current));
+            }
+            return;
+        }
+
+        if current.state == PlayerState::Playing {
+            let step = current.player_position - previous.player_position;
+            if step > 0.0 && step <= MAX_LISTENING_STEP {
+                self.listening_time += step;
+            }
+        }
+    }
+
+    /// 이번 세션에서 가장 많이 재생한 아티스트와 횟수
+    pub fn top_artist(&self) -> Option<(&str, u32)> {
+        self.artist_plays
+            .iter()
+            .filter(|(artist, _)| !artist.is_empty())
+            .max_by_key(|(_, count)| **count)
+            .map(|(artist, count)| (artist.as_str(), *count))
+    }
+}
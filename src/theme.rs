@@ -0,0 +1,106 @@
+//! 테마 모듈
+//! 타이틀, 진행 바, 볼륨 바, 목록 선택 강조 색상을 내장 테마 또는 설정 파일 값으로 정합니다.
+
+use crate::config::ThemeColors;
+use ratatui::style::{Color, Modifier, Style};
+
+/// 화면 강조 색상
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// 타이틀 (앱 이름, 포커스 모드 캡션)
+    pub title: Color,
+    /// 진행 바
+    pub progress: Color,
+    /// 볼륨 바
+    pub volume: Color,
+    /// 목록 선택 항목 배경
+    pub highlight: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            title: Color::Magenta,
+            progress: Color::Magenta,
+            volume: Color::Cyan,
+            highlight: Color::Yellow,
+        }
+    }
+}
+
+/// 내장 테마 이름 (`C`로 순환하는 순서)
+pub const BUILTIN_THEMES: &[&str] = &["default", "mono"];
+
+impl Theme {
+    /// 내장 테마 찾기: "default" | "mono"
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Theme::default()),
+            "mono" => Some(Theme {
+                title: Color::White,
+                progress: Color::Gray,
+                volume: Color::Gray,
+                highlight: Color::White,
+            }),
+            _ => None,
+        }
+    }
+
+    /// 내장 테마 위에 `[colors]` 값 적용 (잘못된 항목은 건너뛰고 설명 목록 반환)
+    pub fn with_colors(mut self, colors: &ThemeColors) -> (Self, Vec<String>) {
+        let mut errors = Vec::new();
+        let fields = [
+            ("title", &colors.title, &mut self.title),
+            ("progress", &colors.progress, &mut self.progress),
+            ("volume", &colors.volume, &mut self.volume),
+            ("highlight", &colors.highlight, &mut self.highlight),
+        ];
+        for (name, value, target) in fields {
+            let Some(value) = value else {
+                continue;
+            };
+            match parse_color(value) {
+                Some(color) => *target = color,
+                None => errors.push(format!("invalid color '{}' for {}", value, name)),
+            }
+        }
+        (self, errors)
+    }
+
+    /// 목록 선택 항목 스타일
+    pub fn highlight_style(&self) -> Style {
+        Style::default().fg(Color::Black).bg(self.highlight).add_modifier(Modifier::BOLD)
+    }
+}
+
+/// 색상 이름 또는 `#rrggbb` 파싱
+pub fn parse_color(value: &str) -> Option<Color> {
+    if let Some(hex) = value.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        return Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?));
+    }
+
+    let color = match value.to_lowercase().replace(['-', '_', ' '], "").as_str() {
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "gray" | "grey" => Color::Gray,
+        "darkgray" | "darkgrey" => Color::DarkGray,
+        "lightred" => Color::LightRed,
+        "lightgreen" => Color::LightGreen,
+        "lightyellow" => Color::LightYellow,
+        "lightblue" => Color::LightBlue,
+        "lightmagenta" => Color::LightMagenta,
+        "lightcyan" => Color::LightCyan,
+        "white" => Color::White,
+        _ => return None,
+    };
+    Some(color)
+}
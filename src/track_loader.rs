@@ -0,0 +1,159 @@
+//! 곡 변경 시 부가 정보 백그라운드 로드 모듈
+//! ffmpeg 디코딩이나 osascript 호출은 몇 초씩 걸릴 수 있으므로 UI 루프에서 기다리지 않고
+//! (보관함 전체를 훑는 대기열 재생과 Up Next 조회도 같은 이유로 여기서 처리)
+//! 별도 스레드에서 처리한 뒤 결과를 채널로 App에 돌려줍니다.
+//! 결과에는 요청한 트랙 ID가 붙어 있어 그 사이 곡이 바뀌었으면 버릴 수 있습니다.
+
+use crate::jxa::{self, Chapter, TrackInfo, UpNext};
+use crate::peaks;
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+/// 백그라운드 로드 결과
+pub enum TrackData {
+    /// 음량 파형 (로컬 파일이 아니거나 디코딩에 실패하면 빈 목록)
+    Loudness { track_id: String, peaks: Vec<f32> },
+    /// 챕터 목록
+    Chapters { track_id: String, chapters: Vec<Chapter> },
+    /// 가사 원문 (가사가 없으면 Ok(None))
+    Lyrics { track_id: String, lyrics: anyhow::Result<Option<String>> },
+    /// 자동 재생 결과 (이어서 재생한 곡 수, 비슷한 곡이 없으면 0)
+    Autoplay { artist: String, queued: anyhow::Result<usize> },
+    /// 다음에 재생될 곡 목록
+    UpNext { up_next: anyhow::Result<UpNext> },
+    /// 현재 곡부터 앨범 나머지를 대기열로 재생한 결과 (`position`은 요청 당시 재생 위치)
+    RestOfAlbum { position: f64, queued: anyhow::Result<RestOfAlbum> },
+    /// 검색 결과를 순서대로 재생한 결과 (재생한 곡 수)
+    SearchQueue { queued: anyhow::Result<usize> },
+    /// 검색 결과 앨범 재생 결과 (보관함에 앨범이 없어 곡만 재생했으면 0)
+    AlbumPlay { played: anyhow::Result<usize> },
+}
+
+/// 앨범 나머지 대기열 재생 결과
+pub enum RestOfAlbum {
+    /// 보관함 앨범에서 현재 곡을 찾지 못함
+    NotFound,
+    /// 이미 앨범 마지막 곡
+    LastTrack,
+    /// 현재 곡 뒤로 남은 곡 수만큼 대기열에 넣음
+    Queued(usize),
+}
+
+/// 트랙 부가 정보 로더 (요청마다 작업 스레드 하나)
+pub struct TrackLoader {
+    tx: Sender<TrackData>,
+    rx: Receiver<TrackData>,
+}
+
+impl Default for TrackLoader {
+    fn default() -> Self {
+        let (tx, rx) = mpsc::channel();
+        TrackLoader { tx, rx }
+    }
+}
+
+impl TrackLoader {
+    /// 현재 트랙 파일을 찾아 `bars`개 구간의 음량 파형 계산
+    pub fn fetch_loudness(&self, track_id: &str, bars: usize) {
+        let track_id = track_id.to_string();
+        let tx = self.tx.clone();
+        std::thread::spawn(move || {
+            let peaks = jxa::get_track_location()
+                .ok()
+                .flatten()
+                .and_then(|location| peaks::compute(Path::new(&location), bars).ok())
+                .unwrap_or_default();
+            let _ = tx.send(TrackData::Loudness { track_id, peaks });
+        });
+    }
+
+    /// 현재 트랙 챕터 목록 가져오기
+    pub fn fetch_chapters(&self, track_id: &str) {
+        let track_id = track_id.to_string();
+        let tx = self.tx.clone();
+        std::thread::spawn(move || {
+            let chapters = jxa::get_chapters().unwrap_or_default();
+            let _ = tx.send(TrackData::Chapters { track_id, chapters });
+        });
+    }
+
+    /// 현재 트랙 가사 가져오기
+    pub fn fetch_lyrics(&self, track_id: &str) {
+        let track_id = track_id.to_string();
+        let tx = self.tx.clone();
+        std::thread::spawn(move || {
+            let lyrics = jxa::get_lyrics();
+            let _ = tx.send(TrackData::Lyrics { track_id, lyrics });
+        });
+    }
+
+    /// `previous`와 비슷한 곡을 최대 `limit`개 찾아 대기열로 재생
+    pub fn start_autoplay(&self, previous: &TrackInfo, limit: usize) {
+        let previous = previous.clone();
+        let tx = self.tx.clone();
+        std::thread::spawn(move || {
+            let ids = jxa::get_similar_tracks(&previous, limit).unwrap_or_default();
+            let queued = if ids.is_empty() {
+                Ok(0)
+            } else {
+                jxa::play_tracks_as_queue(&ids).map(|()| ids.len())
+            };
+            let _ = tx.send(TrackData::Autoplay { artist: previous.artist, queued });
+        });
+    }
+
+    /// 다음에 재생될 곡을 최대 `limit`개 가져오기
+    pub fn fetch_up_next(&self, limit: usize) {
+        let tx = self.tx.clone();
+        std::thread::spawn(move || {
+            let up_next = jxa::get_up_next(limit);
+            let _ = tx.send(TrackData::UpNext { up_next });
+        });
+    }
+
+    /// `track`이 속한 보관함 앨범을 현재 곡부터 끝까지 대기열로 재생
+    pub fn queue_rest_of_album(&self, track: &TrackInfo, position: f64) {
+        let track = track.clone();
+        let tx = self.tx.clone();
+        std::thread::spawn(move || {
+            let tracks = jxa::get_album_tracks(&track.artist, &track.album).unwrap_or_default();
+            let queued = match tracks.iter().position(|t| t.id == track.id) {
+                None => Ok(RestOfAlbum::NotFound),
+                Some(index) if index + 1 == tracks.len() => Ok(RestOfAlbum::LastTrack),
+                Some(index) => {
+                    let ids: Vec<String> = tracks[index..].iter().map(|t| t.id.clone()).collect();
+                    jxa::play_tracks_as_queue(&ids).map(|()| RestOfAlbum::Queued(ids.len() - 1))
+                }
+            };
+            let _ = tx.send(TrackData::RestOfAlbum { position, queued });
+        });
+    }
+
+    /// 검색 결과 트랙들을 순서대로 대기열 재생
+    pub fn play_search_queue(&self, ids: Vec<String>) {
+        let tx = self.tx.clone();
+        std::thread::spawn(move || {
+            let queued = jxa::play_tracks_as_queue(&ids).map(|()| ids.len());
+            let _ = tx.send(TrackData::SearchQueue { queued });
+        });
+    }
+
+    /// 앨범 전체 재생 (보관함에 앨범이 없으면 `track_id` 곡만 재생)
+    pub fn play_album(&self, artist: &str, album: &str, track_id: &str) {
+        let (artist, album, track_id) = (artist.to_string(), album.to_string(), track_id.to_string());
+        let tx = self.tx.clone();
+        std::thread::spawn(move || {
+            let played = jxa::play_album(&artist, &album).inspect(|&count| {
+                if count == 0 {
+                    let _ = jxa::play_track_by_id(&track_id);
+                }
+            });
+            let _ = tx.send(TrackData::AlbumPlay { played });
+        });
+    }
+
+    /// 끝난 로드 결과 하나 가져오기 (없으면 None)
+    pub fn try_recv(&self) -> Option<TrackData> {
+        self.rx.try_recv().ok()
+    }
+}
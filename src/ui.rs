@@ -1,19 +1,88 @@
 //! UI 렌더링 모듈
 
-use crate::app::{App, AppMode, SearchMode};
-use crate::jxa::PlayerState;
+use crate::app::{stars_text, Action, App, AppMode, LayoutMode, ListHitArea, SearchMode};
+use crate::config::{ArtworkBorder, ArtworkFallback, VolumeTarget};
+use crate::jxa::{AudioQuality, PlayerState, RepeatMode, ResultSource, TrackInfo};
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, Paragraph, Clear, List, ListItem, ListState},
+    widgets::{Block, BorderType, Borders, Gauge, Paragraph, Clear, List, ListItem, ListState},
     Frame,
 };
 use ratatui_image::StatefulImage;
-use unicode_width::UnicodeWidthStr;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// UI 렌더링
 pub fn render(frame: &mut Frame, app: &mut App) {
+    // 목록 팝업/버튼이 이번 프레임에 그려질 때만 마우스 클릭 대상이 됨
+    app.list_hit = None;
+    app.button_hits.clear();
+    app.progress_hit = None;
+    app.volume_hit = None;
+
+    // 브라우저는 Now Playing 화면 대신 전체 화면으로 표시
+    if app.mode == AppMode::Browser {
+        render_browser(frame, app);
+        if app.show_help {
+            render_help_overlay(frame, app);
+        }
+        return;
+    }
+
+    match app.layout {
+        LayoutMode::Full => render_full(frame, app),
+        LayoutMode::Focus => render_focus(frame, app),
+        LayoutMode::Compact => render_compact(frame, app),
+    }
+
+    // 일시정지 중이면 전체 화면을 흐리게 (팝업은 그 위에 원래 색으로 표시)
+    if app.config.dim_when_paused && app.track.state == PlayerState::Paused {
+        let area = frame.area();
+        frame.buffer_mut().set_style(area, Style::default().add_modifier(Modifier::DIM));
+    }
+
+    // Music.app이 종료되었으면 화면 정보가 멈춰 있다는 안내
+    if !app.music_running {
+        render_music_banner(frame, app);
+    }
+
+    // 모드별 팝업 렌더링
+    match app.mode {
+        AppMode::Normal => {}
+        AppMode::SearchInput => {
+            // 자동 검색 결과가 있으면 결과 위에 입력창 표시
+            if !app.search_results.is_empty() {
+                render_search_results(frame, app);
+            }
+            render_search_input(frame, app);
+        }
+        AppMode::SearchResults => render_search_results(frame, app),
+        AppMode::SearchFilter => {
+            render_search_results(frame, app);
+            render_search_filter(frame, app);
+        }
+        AppMode::SkipLog => render_skip_log(frame, app),
+        AppMode::AirPlay => render_airplay(frame, app),
+        AppMode::UpNext => render_up_next(frame, app),
+        AppMode::Playlists => render_playlists(frame, app),
+        AppMode::Equalizer => render_equalizer(frame, app),
+        AppMode::SessionStats => render_session_stats(frame, app),
+        AppMode::LibraryStats => render_library_stats(frame, app),
+        AppMode::Lyrics => render_lyrics(frame, app),
+        AppMode::Browser => {}
+        AppMode::Command => render_command(frame, app),
+        AppMode::Confirm { ref message, .. } => render_confirm(frame, message),
+    }
+
+    // 전체 키 도움말은 모든 팝업 위에 표시
+    if app.show_help {
+        render_help_overlay(frame, app);
+    }
+}
+
+/// 기본 레이아웃 렌더링
+fn render_full(frame: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
@@ -26,25 +95,106 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         ])
         .split(frame.area());
 
-    render_title(frame, chunks[0]);
+    render_title(frame, app, chunks[0]);
     render_now_playing(frame, app, chunks[1]);
     render_progress_bar(frame, app, chunks[2]);
     render_volume_bar(frame, app, chunks[3]);
     render_help(frame, chunks[4], app);
+}
+
+/// 포커스 레이아웃 렌더링 (화면 중앙의 큰 아트워크 + 캡션 + 진행 바)
+fn render_focus(frame: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(4),     // 아트워크
+            Constraint::Length(1),  // 캡션
+            Constraint::Length(3),  // 진행 바
+        ])
+        .split(frame.area());
+
+    // 정사각형 유지 (폭 = 높이 * 2) 후 가운데 정렬
+    let area = chunks[0];
+    let width = area.height.saturating_mul(2).min(area.width);
+    let artwork_area = Rect::new(area.x + (area.width - width) / 2, area.y, width, area.height);
+    render_artwork(frame, app, artwork_area);
+
+    let caption = if app.track.name.is_empty() {
+        Line::from(Span::styled("Not playing", Style::default().fg(Color::DarkGray)))
+    } else {
+        Line::from(vec![
+            Span::styled(&app.track.name, Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+            Span::styled("  —  ", Style::default().fg(Color::DarkGray)),
+            Span::styled(&app.track.artist, Style::default().fg(Color::Cyan)),
+        ])
+    };
+    frame.render_widget(Paragraph::new(caption).alignment(Alignment::Center), chunks[1]);
 
-    // 검색 모드일 때 팝업 렌더링
-    if app.mode == AppMode::SearchInput {
-        render_search_input(frame, app);
-    } else if app.mode == AppMode::SearchResults {
-        render_search_results(frame, app);
+    render_progress_bar(frame, app, chunks[2]);
+}
+
+/// 한 줄 레이아웃 렌더링 (예: "▶ Artist - Title [1:23 / 4:56] 50%", 상태 메시지는 다음 줄)
+fn render_compact(frame: &mut Frame, app: &mut App) {
+    let area = frame.area();
+    let icon = match app.track.state {
+        PlayerState::Playing => "▶",
+        PlayerState::Paused => "⏸",
+        PlayerState::Stopped => "⏹",
+    };
+    let position = format!(" [{}] ", position_text(app));
+    let volume = format!("{}%", app.volume);
+
+    // 곡 이름이 길면 가운데를 줄여 시간과 볼륨이 항상 보이게 함
+    let track = if app.track.name.is_empty() {
+        "Not playing".to_string()
+    } else {
+        format!("{} - {}", app.track.artist, app.track.name)
+    };
+    let fixed = icon.width() + 1 + position.width() + volume.width();
+    let track = truncate_to_width(&track, (area.width as usize).saturating_sub(fixed));
+
+    let line = Line::from(vec![
+        Span::styled(format!("{} ", icon), Style::default().fg(app.theme.title)),
+        Span::styled(track, Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+        Span::styled(position, Style::default().fg(Color::DarkGray)),
+        Span::styled(volume, Style::default().fg(app.theme.volume)),
+    ]);
+    frame.render_widget(Paragraph::new(line), Rect { height: area.height.min(1), ..area });
+
+    if let Some(status) = app.status()
+        && area.height > 1
+    {
+        let status_area = Rect { y: area.y + 1, height: 1, ..area };
+        frame.render_widget(Paragraph::new(Span::styled(status, Style::default().fg(Color::Green))), status_area);
     }
 }
 
-/// 타이틀 렌더링
-fn render_title(frame: &mut Frame, area: Rect) {
-    let title = Paragraph::new("🎵 Apple Music Remote")
-        .style(Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD))
-        .block(Block::default().borders(Borders::ALL));
+/// 타이틀 렌더링 (설정 시 현재 곡과 진행 시간 함께 표시)
+fn render_title(frame: &mut Frame, app: &App, area: Rect) {
+    let mut spans = vec![Span::styled(
+        "🎵 Apple Music Remote",
+        Style::default().fg(app.theme.title).add_modifier(Modifier::BOLD),
+    )];
+
+    if let Some(profile) = &app.profile {
+        spans.push(Span::styled(format!(" [{}]", profile), Style::default().fg(Color::DarkGray)));
+    }
+
+    if app.shuffle {
+        spans.push(Span::styled(" 🔀", Style::default().fg(Color::Green)));
+    }
+
+    if app.title_now_playing && !app.track.name.is_empty() {
+        spans.push(Span::styled("  —  ", Style::default().fg(Color::DarkGray)));
+        spans.push(Span::styled(
+            format!("{} - {}", app.track.artist, app.track.name),
+            Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::styled(format!("  {}", position_text(app)), Style::default().fg(Color::DarkGray)));
+    }
+
+    let title = Paragraph::new(Line::from(spans)).block(Block::default().borders(Borders::ALL));
     frame.render_widget(title, area);
 }
 
@@ -58,7 +208,7 @@ fn render_now_playing(frame: &mut Frame, app: &mut App, area: Rect) {
     // 아트워크 크기를 높이 기반으로 계산 (정사각형 유지)
     // 터미널 문자는 대략 가로:세로 = 1:2 비율이므로, 폭 = 높이 * 2
     let artwork_height = inner.height;
-    let artwork_width = (artwork_height as u16).saturating_mul(2).min(inner.width / 2);
+    let artwork_width = artwork_height.saturating_mul(2).min(inner.width / 2);
 
     // 내부를 좌우로 분할 (아트워크 : 정보)
     let content_chunks = Layout::default()
@@ -72,29 +222,162 @@ fn render_now_playing(frame: &mut Frame, app: &mut App, area: Rect) {
     // 아트워크 렌더링
     render_artwork(frame, app, content_chunks[0]);
 
-    // 트랙 정보 렌더링
-    render_track_info(frame, app, content_chunks[1]);
+    // 트랙 정보 렌더링 (설정 시 아래에 재생 버튼)
+    if app.config.show_buttons {
+        let info_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(content_chunks[1]);
+        render_track_info(frame, app, info_chunks[0]);
+        render_transport_buttons(frame, app, info_chunks[1]);
+    } else {
+        render_track_info(frame, app, content_chunks[1]);
+    }
+}
+
+/// 재생 버튼 줄 렌더링 (클릭 판정용 위치를 App에 기록)
+fn render_transport_buttons(frame: &mut Frame, app: &mut App, area: Rect) {
+    let play_label = if app.is_playing() { "[ ⏸ ]" } else { "[ ▶ ]" };
+    let buttons = [
+        ("[ ⏮ ]", Action::PreviousTrack),
+        (play_label, Action::PlayPause),
+        ("[ ⏭ ]", Action::NextTrack),
+    ];
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Length(7),
+            Constraint::Length(7),
+            Constraint::Length(7),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    for (i, (label, action)) in buttons.into_iter().enumerate() {
+        let rect = chunks[i + 1];
+        let button = Paragraph::new(label).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+        frame.render_widget(button, rect);
+        app.button_hits.push((rect, action));
+    }
 }
 
 /// 아트워크 렌더링
 fn render_artwork(frame: &mut Frame, app: &mut App, area: Rect) {
+    let area = render_artwork_frame(frame, app.config.artwork_border, area);
+
     if let Some(ref mut protocol) = app.artwork {
         // 아트워크가 있으면 이미지 렌더링
         let image = StatefulImage::default();
         frame.render_stateful_widget(image, area, protocol);
+    } else if app.artwork_loading {
+        // 아직 찾는 중이면 없음 대신 로딩 표시
+        frame.render_widget(artwork_placeholder("Loading…"), area);
+    } else if app.config.artwork_fallback == ArtworkFallback::Waveform && !app.waveform.is_empty() {
+        // 아트워크가 없으면 트랙별 파형 표시
+        render_waveform(frame, &app.waveform, area);
+    } else if app.track.state != PlayerState::Stopped {
+        // 재생 중이면 움직이는 막대 표시 (일시정지 중에는 멈춘 상태로)
+        let bars = spectrum_heights(app.spectrum_tick, area.width as usize);
+        render_waveform(frame, &bars, area);
     } else {
         // 아트워크가 없으면 플레이스홀더 표시
-        let placeholder = Paragraph::new(vec![
-            Line::from(""),
-            Line::from(""),
-            Line::from("    🎵"),
-            Line::from(""),
-            Line::from("  No Artwork"),
-        ])
-        .style(Style::default().fg(Color::DarkGray))
-        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::DarkGray)));
-        frame.render_widget(placeholder, area);
+        frame.render_widget(artwork_placeholder("No Artwork"), area);
+    }
+}
+
+/// 아트워크 자리에 표시할 음표와 안내 문구
+fn artwork_placeholder(label: &str) -> Paragraph<'_> {
+    Paragraph::new(vec![
+        Line::from(""),
+        Line::from(""),
+        Line::from("    🎵"),
+        Line::from(""),
+        Line::from(format!("  {}", label)),
+    ])
+    .style(Style::default().fg(Color::DarkGray))
+}
+
+/// 실제 오디오와 무관한 장식용 막대 높이 (0.0-1.0)
+/// 막대마다 위상이 다른 사인파를 섞어 프레임 사이에 부드럽게 오르내립니다.
+fn spectrum_heights(tick: u64, bars: usize) -> Vec<f32> {
+    let t = tick as f32;
+    (0..bars)
+        .map(|i| {
+            let x = i as f32;
+            let wave = 0.45
+                + 0.25 * (t * 0.31 + x * 0.9).sin()
+                + 0.15 * (t * 0.53 + x * 2.3).sin()
+                + 0.1 * (t * 0.17 + x * 0.37).sin();
+            // 낮은 음역(왼쪽)을 조금 더 높게
+            let tilt = 1.0 - 0.3 * x / bars.max(1) as f32;
+            (wave * tilt).clamp(0.05, 1.0)
+        })
+        .collect()
+}
+
+/// 파형 막대를 블록 문자로 렌더링 (영역 폭에 맞춰 막대를 샘플링, 1/8칸 단위 높이)
+fn render_waveform(frame: &mut Frame, heights: &[f32], area: Rect) {
+    const EIGHTHS: [&str; 9] = [" ", "▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"];
+    if area.width == 0 || area.height == 0 {
+        return;
     }
+
+    let columns: Vec<usize> = (0..area.width as usize)
+        .map(|x| {
+            let height = heights[x * heights.len() / area.width as usize];
+            (height * area.height as f32 * 8.0).round() as usize
+        })
+        .collect();
+
+    let lines: Vec<Line> = (0..area.height as usize)
+        .map(|row| {
+            // 아래쪽 행부터 채움
+            let base = (area.height as usize - 1 - row) * 8;
+            let text: String = columns
+                .iter()
+                .map(|&eighths| EIGHTHS[eighths.saturating_sub(base).min(8)])
+                .collect();
+            Line::from(text)
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines).style(Style::default().fg(Color::Magenta)), area);
+}
+
+/// 설정된 스타일로 아트워크 테두리를 그리고 안쪽 영역 반환
+fn render_artwork_frame(frame: &mut Frame, border: ArtworkBorder, area: Rect) -> Rect {
+    let border_type = match border {
+        ArtworkBorder::None => return area,
+        ArtworkBorder::Plain | ArtworkBorder::Shadow => BorderType::Plain,
+        ArtworkBorder::Double => BorderType::Double,
+        ArtworkBorder::Rounded => BorderType::Rounded,
+    };
+
+    // 그림자는 오른쪽/아래 한 칸씩 블록 문자로 그림
+    let area = if border == ArtworkBorder::Shadow && area.width > 2 && area.height > 2 {
+        let framed = Rect::new(area.x, area.y, area.width - 1, area.height - 1);
+        let shadow_style = Style::default().fg(Color::DarkGray);
+        let buf = frame.buffer_mut();
+        for y in (framed.y + 1)..=framed.bottom() {
+            buf[(framed.right(), y)].set_symbol("▒").set_style(shadow_style);
+        }
+        for x in (framed.x + 1)..=framed.right() {
+            buf[(x, framed.bottom())].set_symbol("▒").set_style(shadow_style);
+        }
+        framed
+    } else {
+        area
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(border_type)
+        .border_style(Style::default().fg(Color::DarkGray));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    inner
 }
 
 /// 트랙 정보 렌더링
@@ -122,64 +405,300 @@ fn render_track_info(frame: &mut Frame, app: &App, area: Rect) {
             ]),
         ]
     } else {
-        vec![
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("  Title:  ", Style::default().fg(Color::DarkGray)),
-                Span::styled(&app.track.name, Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
-            ]),
-            Line::from(vec![
-                Span::styled("  Artist: ", Style::default().fg(Color::DarkGray)),
-                Span::styled(&app.track.artist, Style::default().fg(Color::Cyan)),
-            ]),
-            Line::from(vec![
-                Span::styled("  Album:  ", Style::default().fg(Color::DarkGray)),
-                Span::styled(&app.track.album, Style::default().fg(Color::Yellow)),
-            ]),
-            Line::from(""),
-            Line::from(vec![
-                Span::raw("  "),
-                Span::styled(state_icon, Style::default().fg(Color::Green)),
-            ]),
-        ]
+        let mut lines = if app.track.composer.is_empty() {
+            // 라벨("  Title:  ")과 좋아요/별점을 뺀 너비보다 긴 제목/앨범만 흘려서 표시
+            let loved = loved_span(&app.track);
+            let rating = rating_span(&app.track);
+            let label_width = "  Title:  ".width();
+            let title_width = (area.width as usize).saturating_sub(label_width + loved.width() + rating.width());
+            let album_width = (area.width as usize).saturating_sub(label_width);
+            let album = album_with_year(&app.track.album, app.track.year);
+            vec![
+                Line::from(""),
+                Line::from(vec![
+                    Span::styled("  Title:  ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(
+                        marquee(&app.track.name, title_width, app.marquee_offset),
+                        Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                    ),
+                    loved,
+                    rating,
+                ]),
+                Line::from(vec![
+                    Span::styled("  Artist: ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(&app.track.artist, Style::default().fg(Color::Cyan)),
+                ]),
+                Line::from(vec![
+                    Span::styled("  Album:  ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(marquee(&album, album_width, app.marquee_offset), Style::default().fg(Color::Yellow)),
+                ]),
+            ]
+        } else {
+            classical_lines(&app.track, area.width as usize, app.marquee_offset)
+        };
+
+        // 장르/발매 연도 (둘 다 없으면 생략)
+        if let Some(genre) = genre_text(&app.track) {
+            lines.push(Line::from(vec![
+                Span::styled("  Genre:  ", Style::default().fg(Color::DarkGray)),
+                Span::styled(genre, Style::default().fg(Color::Gray)),
+            ]));
+        }
+
+        // 오디오 포맷/비트레이트/샘플레이트 (알 수 있는 항목만)
+        if let Some(format) = format_text(&app.track) {
+            lines.push(Line::from(vec![
+                Span::styled("  Format: ", Style::default().fg(Color::DarkGray)),
+                Span::styled(format, Style::default().fg(Color::Gray)),
+            ]));
+        }
+
+        // 이퀄라이저를 켠 경우 현재 프리셋
+        if app.eq.enabled && !app.eq.preset.is_empty() {
+            lines.push(Line::from(vec![
+                Span::styled("  EQ: ", Style::default().fg(Color::DarkGray)),
+                Span::styled(app.eq.preset.clone(), Style::default().fg(Color::Gray)),
+            ]));
+        }
+
+        // 챕터가 있는 트랙이면 현재 챕터 표시
+        if let Some(index) = app.current_chapter_index() {
+            lines.push(Line::from(vec![
+                Span::styled("  Chapter: ", Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    format!("{} ({}/{})", app.chapters[index].name, index + 1, app.chapters.len()),
+                    Style::default().fg(Color::Magenta),
+                ),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::raw("  "),
+            Span::styled(state_icon, Style::default().fg(Color::Green)),
+            quality_badge(&app.track),
+            Span::styled(
+                if app.pause_after_id.is_some() { "  ⏸ after this track" } else { "" },
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::styled(
+                if app.autoplay { "  ∞ autoplay" } else { "" },
+                Style::default().fg(Color::Cyan),
+            ),
+        ]));
+        lines
     };
 
     let paragraph = Paragraph::new(text);
     frame.render_widget(paragraph, area);
 }
 
+/// 작곡가가 있는 트랙(클래식)의 정보 줄: 작곡가를 가장 먼저, 연주자/앨범 아티스트를 구분해 표시
+fn classical_lines(track: &TrackInfo, width: usize, offset: usize) -> Vec<Line<'static>> {
+    // 라벨과 좋아요/별점을 뺀 너비보다 긴 값만 흘려서 표시
+    let loved = loved_span(track);
+    let rating = rating_span(track);
+    let value_width = width.saturating_sub("  Composer:  ".width());
+    let work_width = value_width.saturating_sub(loved.width() + rating.width());
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  Composer:  ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                marquee(&track.composer, value_width, offset),
+                Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("  Work:      ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                marquee(&track.name, work_width, offset),
+                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+            ),
+            loved,
+            rating,
+        ]),
+        Line::from(vec![
+            Span::styled("  Performer: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(marquee(&track.artist, value_width, offset), Style::default().fg(Color::Cyan)),
+        ]),
+    ];
+
+    // 지휘자/오케스트라는 보통 앨범 아티스트에 들어 있음
+    if !track.album_artist.is_empty() && track.album_artist != track.artist {
+        lines.push(Line::from(vec![
+            Span::styled("  Conductor: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(marquee(&track.album_artist, value_width, offset), Style::default().fg(Color::Cyan)),
+        ]));
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled("  Album:     ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            marquee(&album_with_year(&track.album, track.year), value_width, offset),
+            Style::default().fg(Color::Yellow),
+        ),
+    ]));
+    lines
+}
+
+/// 제목 옆 좋아요 표시 (좋아요가 아니면 빈 칸)
+fn loved_span(track: &TrackInfo) -> Span<'static> {
+    if !track.loved {
+        return Span::raw("");
+    }
+    Span::styled("  ♥", Style::default().fg(Color::Red))
+}
+
+/// 제목 옆 별점 (별점이 없으면 빈 칸)
+fn rating_span(track: &TrackInfo) -> Span<'static> {
+    if track.rating == 0 {
+        return Span::raw("");
+    }
+    Span::styled(format!("  {}", stars_text(track.rating)), Style::default().fg(Color::Yellow))
+}
+
+/// 재생 위치 텍스트 ("01:23 / 04:00" 또는 남은 시간 "-02:37 / 04:00")
+fn position_text(app: &App) -> String {
+    let total = format_time(app.track.duration);
+    let position = app.display_position();
+    if app.show_remaining {
+        let remaining = (app.track.duration - position).max(0.0);
+        format!("-{} / {}", format_time(remaining), total)
+    } else {
+        format!("{} / {}", format_time(position), total)
+    }
+}
+
+/// 포맷 정보 문자열 (예: "AAC • 256 kbps • 44.1 kHz", 모두 알 수 없으면 None)
+fn format_text(track: &TrackInfo) -> Option<String> {
+    let mut parts = Vec::new();
+    let format = track.format_name();
+    if !format.is_empty() {
+        parts.push(format);
+    }
+    if track.bit_rate > 0 {
+        parts.push(format!("{} kbps", track.bit_rate));
+    }
+    if track.sample_rate > 0 {
+        parts.push(format!("{} kHz", track.sample_rate as f64 / 1000.0));
+    }
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(" • "))
+    }
+}
+
+/// 무손실/Hi-Res 배지 (알 수 없으면 빈 Span)
+fn quality_badge(track: &TrackInfo) -> Span<'static> {
+    match track.quality() {
+        Some(AudioQuality::HiResLossless) => Span::styled(
+            "  [Hi-Res Lossless]",
+            Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+        ),
+        Some(AudioQuality::Lossless) => Span::styled("  [Lossless]", Style::default().fg(Color::Magenta)),
+        None => Span::raw(""),
+    }
+}
+
 /// 진행 바 렌더링
-fn render_progress_bar(frame: &mut Frame, app: &App, area: Rect) {
+fn render_progress_bar(frame: &mut Frame, app: &mut App, area: Rect) {
+    app.progress_hit = Some(Block::default().borders(Borders::ALL).inner(area));
     let ratio = if app.track.duration > 0.0 {
-        (app.track.player_position / app.track.duration).min(1.0)
+        (app.display_position() / app.track.duration).min(1.0)
     } else {
         0.0
     };
 
-    let current = format_time(app.track.player_position);
-    let total = format_time(app.track.duration);
-    let label = format!("{} / {}", current, total);
+    let label = position_text(app);
+    let repeat = match app.repeat {
+        RepeatMode::Off => "",
+        RepeatMode::All => "🔁 ",
+        RepeatMode::One => "🔂 ",
+    };
+
+    if let Some(peaks) = app.current_loudness() {
+        render_loudness_bar(frame, peaks, ratio, &format!("{}{}", repeat, label), app.theme.progress, area);
+        return;
+    }
 
     let gauge = Gauge::default()
-        .block(Block::default().borders(Borders::ALL).title(" Progress "))
-        .gauge_style(Style::default().fg(Color::Magenta))
+        .block(Block::default().borders(Borders::ALL).title(format!(" Progress {}", repeat)))
+        .gauge_style(Style::default().fg(app.theme.progress))
         .ratio(ratio)
         .label(label);
     frame.render_widget(gauge, area);
 }
 
+/// 음량 파형을 배경으로 한 진행 바 (재생한 부분은 강조, 남은 부분은 흐리게)
+fn render_loudness_bar(frame: &mut Frame, peaks: &[f32], ratio: f64, label: &str, color: Color, area: Rect) {
+    const EIGHTHS: [&str; 9] = [" ", "▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"];
+    let block = Block::default().borders(Borders::ALL).title(format!(" Progress  {} ", label));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+
+    let width = inner.width as usize;
+    let played = (ratio * width as f64).round() as usize;
+    let spans: Vec<Span> = (0..width)
+        .map(|x| {
+            let peak = peaks[x * peaks.len() / width];
+            let symbol = EIGHTHS[((peak * 8.0).round() as usize).clamp(1, 8)];
+            let color = if x < played { color } else { Color::DarkGray };
+            Span::styled(symbol, Style::default().fg(color))
+        })
+        .collect();
+
+    // 한 줄보다 높으면 아래쪽 줄에 맞춤
+    let row = Rect::new(inner.x, inner.y + inner.height - 1, inner.width, 1);
+    frame.render_widget(Paragraph::new(Line::from(spans)), row);
+}
+
 /// 볼륨 바 렌더링
-fn render_volume_bar(frame: &mut Frame, app: &App, area: Rect) {
+fn render_volume_bar(frame: &mut Frame, app: &mut App, area: Rect) {
+    app.volume_hit = Some(Block::default().borders(Borders::ALL).inner(area));
+    let title = match app.volume_target {
+        VolumeTarget::App => " Volume ",
+        VolumeTarget::System => " Volume (System) ",
+    };
     let gauge = Gauge::default()
-        .block(Block::default().borders(Borders::ALL).title(" Volume "))
-        .gauge_style(Style::default().fg(Color::Cyan))
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .gauge_style(Style::default().fg(app.theme.volume))
         .percent(app.volume as u16)
-        .label(format!("{}%", app.volume));
+        .label(volume_label(app));
     frame.render_widget(gauge, area);
 }
 
-/// 도움말 렌더링
+/// 볼륨 라벨 (시스템 출력 음소거, 덕킹 상태 표시)
+fn volume_label(app: &App) -> String {
+    if app.muted {
+        return "MUTED".to_string();
+    }
+    let mut label = format!("{}%", app.volume);
+    if app.system_muted {
+        label.push_str(" (sys muted)");
+    }
+    if let Some(previous) = app.duck_restore {
+        label.push_str(&format!(" (ducked from {}%)", previous));
+    }
+    label
+}
+
+/// 도움말 렌더링 (상태 메시지가 있으면 대신 표시)
 fn render_help(frame: &mut Frame, area: Rect, app: &App) {
+    if let Some(status) = app.status() {
+        let status = Paragraph::new(Line::from(Span::styled(
+            format!(" {}", status),
+            Style::default().fg(Color::Green),
+        )))
+        .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(status, area);
+        return;
+    }
+
     let help_text = match app.mode {
         AppMode::Normal => vec![
             Span::styled(" ␣ ", Style::default().fg(Color::Yellow)),
@@ -187,9 +706,11 @@ fn render_help(frame: &mut Frame, area: Rect, app: &App) {
             Span::styled("←/→ ", Style::default().fg(Color::Yellow)),
             Span::raw("Prev/Next  "),
             Span::styled("↑/↓ ", Style::default().fg(Color::Yellow)),
-            Span::raw("Volume  "),
+            Span::raw(format!("Volume ±{}  ", app.config.volume_step)),
             Span::styled("/ ", Style::default().fg(Color::Yellow)),
             Span::raw("Search  "),
+            Span::styled("? ", Style::default().fg(Color::Yellow)),
+            Span::raw("Help  "),
             Span::styled("q ", Style::default().fg(Color::Red)),
             Span::raw("Quit"),
         ],
@@ -198,23 +719,128 @@ fn render_help(frame: &mut Frame, area: Rect, app: &App) {
             Span::raw("Search  "),
             Span::styled(" Tab ", Style::default().fg(Color::Yellow)),
             Span::raw("Switch Source  "),
+            Span::styled("^P ", Style::default().fg(Color::Yellow)),
+            Span::raw(if app.search_playlist.is_some() { "Whole Library  " } else { "In Playlist  " }),
             Span::styled("Esc ", Style::default().fg(Color::Yellow)),
             Span::raw("Cancel"),
         ],
         AppMode::SearchResults => {
-            let action_label = match app.search_mode {
-                SearchMode::Library => "Play  ",
-                SearchMode::AppleMusic => "Open in Music  ",
+            let selected = app.search_results.get(app.search_result_index);
+            let action_label = match selected.map(|result| result.source) {
+                Some(ResultSource::AppleMusic) => "Open in Music  ",
+                _ => "Play  ",
             };
             vec![
                 Span::styled(" ↑/↓ ", Style::default().fg(Color::Yellow)),
                 Span::raw("Move  "),
+                Span::styled("PgUp/PgDn ", Style::default().fg(Color::Yellow)),
+                Span::raw("Page  "),
                 Span::styled("Enter ", Style::default().fg(Color::Yellow)),
                 Span::raw(action_label),
+                Span::styled("a ", Style::default().fg(Color::Yellow)),
+                Span::raw("Play All  "),
+                Span::styled("A ", Style::default().fg(Color::Yellow)),
+                Span::raw("Album  "),
+                Span::styled("n ", Style::default().fg(Color::Yellow)),
+                Span::raw("Next  "),
+                Span::styled("f ", Style::default().fg(Color::Yellow)),
+                Span::raw("Filter  "),
+                Span::styled("/ ", Style::default().fg(Color::Yellow)),
+                Span::raw("Refine  "),
                 Span::styled("Esc ", Style::default().fg(Color::Yellow)),
                 Span::raw("Cancel"),
             ]
         },
+        AppMode::SearchFilter => vec![
+            Span::styled(" Enter ", Style::default().fg(Color::Yellow)),
+            Span::raw("Apply  "),
+            Span::styled("Esc ", Style::default().fg(Color::Yellow)),
+            Span::raw("Cancel"),
+        ],
+        AppMode::Command => vec![
+            Span::styled(" Enter ", Style::default().fg(Color::Yellow)),
+            Span::raw("Run  "),
+            Span::styled("Esc ", Style::default().fg(Color::Yellow)),
+            Span::raw("Cancel"),
+        ],
+        AppMode::SkipLog => vec![
+            Span::styled(" ↑/↓ ", Style::default().fg(Color::Yellow)),
+            Span::raw("Move  "),
+            Span::styled("D ", Style::default().fg(Color::Yellow)),
+            Span::raw("Clear  "),
+            Span::styled("Esc ", Style::default().fg(Color::Yellow)),
+            Span::raw("Close"),
+        ],
+        AppMode::AirPlay => vec![
+            Span::styled(" ↑/↓ ", Style::default().fg(Color::Yellow)),
+            Span::raw("Move  "),
+            Span::styled("←/→ ", Style::default().fg(Color::Yellow)),
+            Span::raw("Volume  "),
+            Span::styled("␣ ", Style::default().fg(Color::Yellow)),
+            Span::raw("Add/Remove  "),
+            Span::styled("Esc ", Style::default().fg(Color::Yellow)),
+            Span::raw("Close"),
+        ],
+        AppMode::UpNext => vec![
+            Span::styled(" ↑/↓ ", Style::default().fg(Color::Yellow)),
+            Span::raw("Move  "),
+            Span::styled("Esc ", Style::default().fg(Color::Yellow)),
+            Span::raw("Close"),
+        ],
+        AppMode::Playlists => vec![
+            Span::styled(" ↑/↓ ", Style::default().fg(Color::Yellow)),
+            Span::raw("Move  "),
+            Span::styled("Enter ", Style::default().fg(Color::Yellow)),
+            Span::raw(if app.playlists_pick_scope { "Search Here  " } else { "Play  " }),
+            Span::styled("Esc ", Style::default().fg(Color::Yellow)),
+            Span::raw("Close"),
+        ],
+        AppMode::Equalizer => vec![
+            Span::styled(" ↑/↓ ", Style::default().fg(Color::Yellow)),
+            Span::raw("Move  "),
+            Span::styled("Enter ", Style::default().fg(Color::Yellow)),
+            Span::raw("Apply  "),
+            Span::styled("␣ ", Style::default().fg(Color::Yellow)),
+            Span::raw(if app.eq.enabled { "EQ Off  " } else { "EQ On  " }),
+            Span::styled("Esc ", Style::default().fg(Color::Yellow)),
+            Span::raw("Close"),
+        ],
+        AppMode::SessionStats => vec![
+            Span::styled(" Esc ", Style::default().fg(Color::Yellow)),
+            Span::raw("Close"),
+        ],
+        AppMode::LibraryStats => vec![
+            Span::styled(" r ", Style::default().fg(Color::Yellow)),
+            Span::raw("Recount  "),
+            Span::styled("Esc ", Style::default().fg(Color::Yellow)),
+            Span::raw("Close"),
+        ],
+        AppMode::Browser => vec![
+            Span::styled(" ↑/↓ ", Style::default().fg(Color::Yellow)),
+            Span::raw("Move  "),
+            Span::styled("→/Enter ", Style::default().fg(Color::Yellow)),
+            Span::raw("Open/Play  "),
+            Span::styled("← ", Style::default().fg(Color::Yellow)),
+            Span::raw("Back  "),
+            Span::styled("␣ ", Style::default().fg(Color::Yellow)),
+            Span::raw("Play/Pause  "),
+            Span::styled("Esc ", Style::default().fg(Color::Yellow)),
+            Span::raw("Now Playing"),
+        ],
+        AppMode::Lyrics => vec![
+            Span::styled(" ↑/↓ ", Style::default().fg(Color::Yellow)),
+            Span::raw("Scroll  "),
+            Span::styled("PgUp/PgDn ", Style::default().fg(Color::Yellow)),
+            Span::raw("Page  "),
+            Span::styled("Esc ", Style::default().fg(Color::Yellow)),
+            Span::raw("Close"),
+        ],
+        AppMode::Confirm { .. } => vec![
+            Span::styled(" y ", Style::default().fg(Color::Yellow)),
+            Span::raw("Yes  "),
+            Span::styled("n/Esc ", Style::default().fg(Color::Yellow)),
+            Span::raw("No"),
+        ],
     };
 
     let help = Paragraph::new(Line::from(help_text))
@@ -224,22 +850,38 @@ fn render_help(frame: &mut Frame, area: Rect, app: &App) {
 
 /// 검색 입력창 렌더링 (화면 중앙 팝업)
 fn render_search_input(frame: &mut Frame, app: &App) {
-    let area = centered_rect(60, 20, frame.area()); 
-    let height = 3;
-    let y_pos = area.y + (area.height - height) / 2;
-    let input_area = Rect::new(area.x, y_pos, area.width, height);
+    let input_area = if app.search_results.is_empty() {
+        let area = popup_rect(60, 20, SEARCH_POPUP_MIN_WIDTH, SEARCH_INPUT_HEIGHT, frame.area());
+        let height = SEARCH_INPUT_HEIGHT.min(area.height);
+        let y_pos = area.y + (area.height - height) / 2;
+        Rect::new(area.x, y_pos, area.width, height)
+    } else {
+        // 결과 미리 보기 팝업 위쪽에 붙여서 표시
+        let area = popup_rect(60, 50, SEARCH_POPUP_MIN_WIDTH, SEARCH_RESULTS_MIN_HEIGHT, frame.area());
+        Rect::new(area.x, area.y.saturating_sub(SEARCH_INPUT_HEIGHT), area.width, SEARCH_INPUT_HEIGHT.min(frame.area().height))
+    };
 
     frame.render_widget(Clear, input_area); // 배경 지우기
 
     let title = match app.search_mode {
-        SearchMode::Library => " Search Library (Tab to switch) ",
-        SearchMode::AppleMusic => " Search Apple Music (Tab to switch) ",
+        SearchMode::Library => format!(" Search {} (Tab to switch) ", library_label(app)),
+        SearchMode::AppleMusic => " Search Apple Music (Tab to switch) ".to_string(),
+        SearchMode::Combined => format!(" Search {} + Apple Music (Tab to switch) ", library_label(app)),
     };
 
-    let block = Block::default()
+    let mut block = Block::default()
         .borders(Borders::ALL)
         .title(title)
         .border_style(Style::default().fg(Color::Yellow));
+    if let Some(spinner) = app.search_spinner() {
+        block = block.title_bottom(format!(" {} Searching… (Esc to cancel) ", spinner));
+    } else if app.search_performed && app.search_results.is_empty() {
+        // 검색은 끝났지만 결과가 없으면 아무 일도 없었던 것처럼 보이지 않도록 안내
+        block = block.title_bottom(Span::styled(
+            format!(" No results for '{}' ", app.search_query.trim()),
+            Style::default().fg(Color::Red),
+        ));
+    }
 
     let input = Paragraph::new(app.search_query.as_str())
         .block(block)
@@ -248,23 +890,56 @@ fn render_search_input(frame: &mut Frame, app: &App) {
     frame.render_widget(input, input_area);
 
     // 커서 표시 (width() 사용을 위해 unicode-width crate 필요)
-    let cursor_x = input_area.x + 1 + app.search_query.width() as u16;
-    let cursor_y = input_area.y + 1;
-    frame.set_cursor(cursor_x, cursor_y); 
+    // 입력이 창보다 길거나 화면이 아주 작아도 커서가 입력창 안에 있도록 제한
+    let cursor_x = (input_area.x + 1 + app.search_query.width() as u16).min(input_area.right().saturating_sub(2));
+    let cursor_y = (input_area.y + 1).min(input_area.bottom().saturating_sub(1));
+    frame.set_cursor_position((cursor_x, cursor_y));
+}
+
+/// 보관함 검색 범위 이름 (예: "Library", "Playlist 'Chill'")
+fn library_label(app: &App) -> String {
+    match &app.search_playlist {
+        Some(playlist) => format!("Playlist '{}'", playlist.name),
+        None => "Library".to_string(),
+    }
 }
 
 /// 검색 결과 리스트 렌더링 (화면 중앙 팝업)
 fn render_search_results(frame: &mut Frame, app: &mut App) {
-    let area = centered_rect(60, 50, frame.area());
+    let area = popup_rect(60, 50, SEARCH_POPUP_MIN_WIDTH, SEARCH_RESULTS_MIN_HEIGHT, frame.area());
     frame.render_widget(Clear, area);
 
+    let combined = app.search_mode == SearchMode::Combined;
+    // 테두리와 선택 표시(">> ")를 뺀 한 줄 너비
+    let text_width = (area.width as usize).saturating_sub(2 + ">> ".len());
     let items: Vec<ListItem> = app.search_results
         .iter()
         .map(|track| {
+            // 함께 검색한 경우 행마다 출처 표시
+            let source = match (combined, track.source) {
+                (false, _) => Span::raw(""),
+                (true, ResultSource::Library) => Span::styled("[Lib] ", Style::default().fg(Color::Green)),
+                (true, ResultSource::AppleMusic) => Span::styled("[AM]  ", Style::default().fg(Color::Red)),
+            };
+            let duration = if track.duration > 0.0 { format!(" {}", format_time(track.duration)) } else { String::new() };
+            let copies = if track.duplicates > 0 { format!(" ({} copies)", track.duplicates + 1) } else { String::new() };
+            let album = album_with_year(&track.album, track.year);
+
+            // 고정 부분을 뺀 너비를 제목/아티스트/앨범에 나눠 가운데를 줄임
+            let fixed = source.width() + " - ".len() + " ".len() + "()".len() + duration.width() + copies.width();
+            let budget = text_width.saturating_sub(fixed);
+            let fitted = fit_widths(&[track.name.width(), track.artist.width(), album.width()], budget);
+
             let content = Line::from(vec![
-                Span::styled(format!("{} - ", track.name), Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(format!("{} ", track.artist)),
-                Span::styled(format!("({})", track.album), Style::default().fg(Color::DarkGray)),
+                source,
+                Span::styled(
+                    format!("{} - ", truncate_to_width(&track.name, fitted[0])),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!("{} ", truncate_to_width(&track.artist, fitted[1]))),
+                Span::styled(format!("({})", truncate_to_width(&album, fitted[2])), Style::default().fg(Color::DarkGray)),
+                Span::styled(duration, Style::default().fg(Color::DarkGray)),
+                Span::styled(copies, Style::default().fg(Color::Magenta)),
             ]);
             ListItem::new(content)
         })
@@ -274,17 +949,665 @@ fn render_search_results(frame: &mut Frame, app: &mut App) {
     let mut state = ListState::default();
     state.select(Some(app.search_result_index));
 
-    let title = match app.search_mode {
-        SearchMode::Library => " Search Results (Library) ",
-        SearchMode::AppleMusic => " Search Results (Apple Music) ",
+    let source = match app.search_mode {
+        SearchMode::Library => library_label(app),
+        SearchMode::AppleMusic => "Apple Music".to_string(),
+        SearchMode::Combined => format!("{} + Apple Music", library_label(app)),
+    };
+    let title = match app.search_filter {
+        Some(filter) => format!(" Search Results ({}) [{}: {} matches] ", source, filter.label(), app.search_results.len()),
+        None => format!(" Search Results ({}) ", source),
     };
 
     let list = List::new(items)
         .block(Block::default().borders(Borders::ALL).title(title))
-        .highlight_style(Style::default().fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD))
+        .highlight_style(app.theme.highlight_style())
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(list, area, &mut state);
+    app.list_hit = Some(ListHitArea { area, offset: state.offset() });
+}
+
+/// 검색 결과 필터 입력창 렌더링 (결과 팝업 하단)
+fn render_search_filter(frame: &mut Frame, app: &App) {
+    let area = popup_rect(60, 50, SEARCH_POPUP_MIN_WIDTH, SEARCH_RESULTS_MIN_HEIGHT, frame.area());
+    let height = SEARCH_INPUT_HEIGHT.min(area.height);
+    let input_area = Rect::new(area.x, (area.y + area.height).saturating_sub(height), area.width, height);
+
+    frame.render_widget(Clear, input_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Filter (2-4, 2:30-4:15, 120-140bpm; empty to clear) ")
+        .border_style(Style::default().fg(Color::Yellow));
+
+    let input = Paragraph::new(app.search_filter_input.as_str())
+        .block(block)
+        .style(Style::default().fg(Color::White));
+
+    frame.render_widget(input, input_area);
+
+    let cursor_x = input_area.x + 1 + app.search_filter_input.width() as u16;
+    frame.set_cursor_position((cursor_x, input_area.y + 1));
+}
+
+/// 명령 팔레트 입력창 렌더링
+fn render_command(frame: &mut Frame, app: &App) {
+    let area = popup_rect(60, 20, SEARCH_POPUP_MIN_WIDTH, SEARCH_INPUT_HEIGHT, frame.area());
+    let height = SEARCH_INPUT_HEIGHT.min(area.height);
+    let input_area = Rect::new(area.x, area.y + (area.height - height) / 2, area.width, height);
+
+    frame.render_widget(Clear, input_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Command (shuffle, repeat all, volume 80, seek 1:30, lyrics...) ")
+        .border_style(Style::default().fg(Color::Yellow));
+
+    let input = Paragraph::new(format!(":{}", app.command_input))
+        .block(block)
+        .style(Style::default().fg(Color::White));
+
+    frame.render_widget(input, input_area);
+
+    let cursor_x = (input_area.x + 2 + app.command_input.width() as u16).min(input_area.right().saturating_sub(2));
+    frame.set_cursor_position((cursor_x, input_area.y + 1));
+}
+
+/// 브라우저에 동시에 보여줄 최대 열 수
+const BROWSER_COLUMNS: usize = 3;
+
+/// 라이브러리 브라우저 렌더링 (경로 + 최근 단계들을 여러 열로 + 도움말)
+fn render_browser(frame: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3),  // 경로
+            Constraint::Min(5),     // 열
+            Constraint::Length(3),  // 도움말
+        ])
+        .split(frame.area());
+
+    let mut crumb = vec![Span::styled(" 📚 ", Style::default().fg(Color::Magenta))];
+    crumb.push(Span::styled(app.browser.breadcrumb(), Style::default().fg(Color::White).add_modifier(Modifier::BOLD)));
+    if !app.track.name.is_empty() {
+        crumb.push(Span::styled(
+            format!("   ♪ {} - {}", app.track.artist, app.track.name),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(crumb)).block(Block::default().borders(Borders::ALL)), chunks[0]);
+
+    // 가장 안쪽 단계가 오른쪽 끝 열에 오도록 최근 단계들만 표시
+    let start = app.browser.stack.len().saturating_sub(BROWSER_COLUMNS);
+    let levels = &app.browser.stack[start..];
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, levels.len() as u32); levels.len()])
+        .split(chunks[1]);
+
+    let mut focused_hit = None;
+    for (i, level) in levels.iter().enumerate() {
+        let focused = i == levels.len() - 1;
+        let items: Vec<ListItem> = level.items.iter().map(|item| ListItem::new(item.as_str())).collect();
+        let highlight = if focused {
+            app.theme.highlight_style()
+        } else {
+            Style::default().fg(app.theme.highlight)
+        };
+        let border = if focused { app.theme.highlight } else { Color::DarkGray };
+
+        let mut state = ListState::default();
+        state.select(Some(level.index));
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(border)).title(format!(" {} ", level.title)))
+            .highlight_style(highlight)
+            .highlight_symbol(if focused { ">> " } else { "   " });
+        frame.render_stateful_widget(list, columns[i], &mut state);
+
+        if focused {
+            focused_hit = Some(ListHitArea { area: columns[i], offset: state.offset() });
+        }
+    }
+    app.list_hit = focused_hit;
+
+    render_help(frame, chunks[2], app);
+}
+
+/// 스킵 기록 리스트 렌더링 (화면 중앙 팝업)
+fn render_skip_log(frame: &mut Frame, app: &mut App) {
+    let area = centered_rect(60, 50, frame.area());
+    frame.render_widget(Clear, area);
+
+    let block = Block::default().borders(Borders::ALL).title(" Frequently Skipped ");
+
+    let entries = app.skip_log.most_skipped();
+    if entries.is_empty() {
+        let empty = Paragraph::new("  No skipped tracks yet")
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = entries
+        .iter()
+        .map(|entry| {
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{:>3}x ", entry.count), Style::default().fg(Color::Red)),
+                Span::styled(format!("{} - ", entry.name), Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(&entry.artist),
+            ]))
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(app.skip_log_index));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(app.theme.highlight_style())
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(list, area, &mut state);
+    app.list_hit = Some(ListHitArea { area, offset: state.offset() });
+}
+
+/// AirPlay 장치별 볼륨 슬라이더 폭
+const AIRPLAY_SLIDER_WIDTH: usize = 10;
+
+/// AirPlay 장치 목록 렌더링 (화면 중앙 팝업)
+/// 출력 그룹 포함 여부와 장치별 볼륨 슬라이더를 함께 표시
+fn render_airplay(frame: &mut Frame, app: &mut App) {
+    let area = centered_rect(60, 50, frame.area());
+    frame.render_widget(Clear, area);
+
+    let items: Vec<ListItem> = app.airplay_devices
+        .iter()
+        .map(|device| {
+            let (check, check_style) = if device.selected {
+                ("[x] ", Style::default().fg(Color::Green))
+            } else {
+                ("[ ] ", Style::default().fg(Color::DarkGray))
+            };
+            let filled = device.volume as usize * AIRPLAY_SLIDER_WIDTH / 100;
+            let kind = if device.kind.is_empty() { String::new() } else { format!(" ({})", device.kind) };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(check, check_style),
+                Span::styled(device.name.clone(), Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(kind, Style::default().fg(Color::DarkGray)),
+                Span::raw("  "),
+                Span::styled("█".repeat(filled), Style::default().fg(Color::Cyan)),
+                Span::styled("░".repeat(AIRPLAY_SLIDER_WIDTH - filled), Style::default().fg(Color::DarkGray)),
+                Span::raw(format!(" {:>3}%", device.volume)),
+            ]))
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(app.airplay_index));
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(" AirPlay Outputs "))
+        .highlight_style(app.theme.highlight_style())
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(list, area, &mut state);
+    app.list_hit = Some(ListHitArea { area, offset: state.offset() });
+}
+
+/// Up Next 목록 렌더링 (화면 중앙 팝업, 현재 곡 강조)
+fn render_up_next(frame: &mut Frame, app: &mut App) {
+    let area = popup_rect(60, 60, SEARCH_POPUP_MIN_WIDTH, SEARCH_RESULTS_MIN_HEIGHT, frame.area());
+    frame.render_widget(Clear, area);
+    let block = Block::default().borders(Borders::ALL).title(" Up Next ");
+
+    let tracks = match &app.up_next {
+        Some(up_next) if !up_next.tracks.is_empty() => &up_next.tracks,
+        up_next => {
+            let text = if app.up_next_loading {
+                "  Loading Up Next…"
+            } else if up_next.as_ref().is_some_and(|up_next| up_next.shuffled) {
+                "  Shuffle is on — the upcoming order isn't known"
+            } else {
+                "  Up Next isn't available for what's playing right now"
+            };
+            let message = Paragraph::new(text)
+                .style(Style::default().fg(Color::DarkGray))
+                .block(block);
+            frame.render_widget(message, area);
+            return;
+        }
+    };
+
+    let items: Vec<ListItem> = tracks
+        .iter()
+        .enumerate()
+        .map(|(i, track)| {
+            let (marker, style) = if i == 0 {
+                ("▶ ", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+            } else {
+                ("  ", Style::default())
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(marker, Style::default().fg(Color::Green)),
+                Span::styled(format!("{} - ", track.name), style.add_modifier(Modifier::BOLD)),
+                Span::styled(track.artist.clone(), style),
+                Span::styled(format!(" {}", format_time(track.duration)), Style::default().fg(Color::DarkGray)),
+            ]))
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(app.up_next_index));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(app.theme.highlight_style())
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(list, area, &mut state);
+    app.list_hit = Some(ListHitArea { area, offset: state.offset() });
+}
+
+/// 플레이리스트 목록 렌더링 (화면 중앙 팝업)
+fn render_playlists(frame: &mut Frame, app: &mut App) {
+    let area = popup_rect(50, 60, SEARCH_POPUP_MIN_WIDTH, SEARCH_RESULTS_MIN_HEIGHT, frame.area());
+    frame.render_widget(Clear, area);
+    let title = if app.playlists_pick_scope { " Search in Playlist " } else { " Playlists " };
+    let block = Block::default().borders(Borders::ALL).title(title);
+
+    if app.playlists.is_empty() {
+        let message = Paragraph::new("  No playlists in your library")
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(message, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app.playlists.iter().map(|playlist| ListItem::new(playlist.name.as_str())).collect();
+
+    let mut state = ListState::default();
+    state.select(Some(app.playlist_index));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(app.theme.highlight_style())
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(list, area, &mut state);
+    app.list_hit = Some(ListHitArea { area, offset: state.offset() });
+}
+
+/// 이퀄라이저 프리셋 목록 렌더링 (화면 중앙 팝업, 현재 프리셋에 ● 표시)
+fn render_equalizer(frame: &mut Frame, app: &mut App) {
+    let area = popup_rect(40, 60, SEARCH_POPUP_MIN_WIDTH, SEARCH_RESULTS_MIN_HEIGHT, frame.area());
+    frame.render_widget(Clear, area);
+    let title = format!(" Equalizer ({}) ", if app.eq.enabled { "on" } else { "off" });
+    let block = Block::default().borders(Borders::ALL).title(title);
+
+    let items: Vec<ListItem> = app
+        .eq_presets
+        .iter()
+        .map(|name| {
+            let marker = if *name == app.eq.preset { "● " } else { "  " };
+            ListItem::new(format!("{}{}", marker, name))
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(app.eq_index));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(app.theme.highlight_style())
         .highlight_symbol(">> ");
 
     frame.render_stateful_widget(list, area, &mut state);
+    app.list_hit = Some(ListHitArea { area, offset: state.offset() });
+}
+
+/// 도움말 오버레이의 기본 모드 동작 (키는 현재 키맵에서 찾으므로 `[keys]` 설정도 반영)
+const HELP_ACTIONS: &[(&[Action], &str)] = &[
+    (&[Action::PlayPause], "Play / pause"),
+    (&[Action::PreviousTrack], "Previous track"),
+    (&[Action::NextTrack], "Next track"),
+    (&[Action::PreviousChapter], "Previous chapter (or back 30s)"),
+    (&[Action::NextChapter], "Next chapter (or forward 30s)"),
+    (&[Action::SeekBackward], "Seek back 10s"),
+    (&[Action::SeekForward], "Seek forward 10s"),
+    (&[Action::SeekBackwardLong], "Seek back 30s"),
+    (&[Action::SeekForwardLong], "Seek forward 30s"),
+    (&[Action::SeekPercentPrefix], "Then 0-9: jump to 0%-90%"),
+    (&[Action::VolumeUp], "Volume up"),
+    (&[Action::VolumeDown], "Volume down"),
+    (&[Action::VolumeUpFine], "Volume up by 1"),
+    (&[Action::VolumeDownFine], "Volume down by 1"),
+    (&[Action::ToggleMute], "Mute / unmute"),
+    (&[Action::Duck], "Duck volume"),
+    (&[Action::ToggleVolumeTarget], "Switch volume target (app / system)"),
+    (
+        &[Action::Rate(0), Action::Rate(1), Action::Rate(2), Action::Rate(3), Action::Rate(4), Action::Rate(5)],
+        "Rate track (0 clears)",
+    ),
+    (&[Action::ToggleLoved], "Love / unlove track"),
+    (&[Action::ToggleShuffle], "Toggle shuffle"),
+    (&[Action::CycleRepeat], "Cycle repeat mode"),
+    (&[Action::PauseAfterCurrent], "Pause after current track"),
+    (&[Action::ToggleAutoplay], "Toggle autoplay of similar tracks"),
+    (&[Action::QueueRestOfAlbum], "Play rest of album"),
+    (&[Action::SkipAndRemember], "Skip and remember"),
+    (&[Action::Search], "Search"),
+    (&[Action::OpenPlaylists], "Playlists"),
+    (&[Action::OpenEqualizer], "Equalizer presets"),
+    (&[Action::OpenCommand], "Command palette (shuffle, repeat all, volume 80, seek 1:30, ...)"),
+    (&[Action::OpenBrowser], "Library browser"),
+    (&[Action::OpenUpNext], "Up Next"),
+    (&[Action::OpenLyrics], "Lyrics"),
+    (&[Action::OpenSkipLog], "Skip log"),
+    (&[Action::OpenAirPlay], "AirPlay devices"),
+    (&[Action::OpenSessionStats], "Session stats"),
+    (&[Action::OpenLibraryStats], "Library stats (tracks, total time, playlists)"),
+    (&[Action::ExportHistory], "Export session history"),
+    (&[Action::CycleArtwork], "Next artwork candidate"),
+    (&[Action::ToggleSlideshow], "Toggle artwork slideshow"),
+    (&[Action::ToggleFocus], "Toggle focus layout"),
+    (&[Action::ToggleCompact], "Toggle one-line layout"),
+    (&[Action::ToggleTitleNowPlaying], "Show track in title"),
+    (&[Action::ToggleShowRemaining], "Show remaining time"),
+    (&[Action::CycleProfile], "Next profile"),
+    (&[Action::CycleTheme], "Next theme"),
+    (&[Action::CycleVolumeStep], "Change volume step"),
+    (&[Action::Refresh], "Refresh now"),
+    (&[Action::RelaunchMusic], "Relaunch Music.app"),
+    (&[Action::ToggleHelp], "Help"),
+    (&[Action::Quit], "Quit"),
+];
+
+/// 도움말 오버레이의 팝업/화면별 키 (모드 이름, [(키, 설명)])
+const HELP_MODES: &[(&str, &[(&str, &str)])] = &[
+    (
+        "Search",
+        &[("Enter", "Search"), ("Tab", "Switch source"), ("Ctrl-P", "Search in a playlist / whole library"), ("Esc", "Cancel")],
+    ),
+    (
+        "Search results",
+        &[
+            ("↑/↓ j/k", "Move"),
+            ("PgUp/PgDn ^U/^D", "Move one page"),
+            ("Home/End gg/G", "First / last result"),
+            ("5j 5k 5G", "Move 5 down / up, go to result 5"),
+            ("Enter", "Play"),
+            ("a", "Play from selection"),
+            ("A", "Play whole album"),
+            ("n", "Play next (while playing a search queue)"),
+            ("f", "Filter by duration / BPM"),
+            ("/", "Edit query"),
+            ("Esc", "Close"),
+        ],
+    ),
+    ("Playlists", &[("↑/↓ j/k", "Move"), ("Enter", "Play"), ("Esc p", "Close")]),
+    ("Library stats", &[("r", "Recount"), ("Esc i", "Close")]),
+    ("Command palette", &[("Enter", "Run command"), ("Esc", "Cancel")]),
+    ("Equalizer", &[("↑/↓ j/k", "Move"), ("Enter", "Apply preset"), ("Space", "EQ on / off"), ("Esc e", "Close")]),
+    (
+        "Library browser",
+        &[
+            ("↑/↓ j/k", "Move"),
+            ("→ l Enter", "Open / play"),
+            ("← h Backspace", "Back"),
+            ("Space", "Play / pause"),
+            ("Esc B", "Close"),
+            ("q", "Quit"),
+        ],
+    ),
+    ("Up Next", &[("↑/↓ j/k", "Move"), ("Esc u", "Close")]),
+    ("Lyrics", &[("↑/↓ j/k", "Scroll"), ("PgUp/PgDn", "Scroll page"), ("Esc L", "Close")]),
+    ("Skip log", &[("↑/↓ j/k", "Move"), ("D", "Clear"), ("Esc X", "Close")]),
+    (
+        "AirPlay",
+        &[("↑/↓ j/k", "Move"), ("←/→ h/l", "Device volume"), ("Space Enter", "Add / remove"), ("Esc o", "Close")],
+    ),
+    ("Help", &[("↑/↓ j/k", "Scroll"), ("PgUp/PgDn", "Scroll page"), ("Esc ?", "Close")]),
+];
+
+/// 도움말 키 열 너비
+const HELP_KEY_WIDTH: usize = 16;
+
+/// 전체 키 도움말 오버레이 렌더링 (화면 중앙 팝업, 스크롤 가능)
+fn render_help_overlay(frame: &mut Frame, app: &mut App) {
+    let area = popup_rect(60, 80, 40, 10, frame.area());
+    frame.render_widget(Clear, area);
+    let block = Block::default().borders(Borders::ALL).title(" Help (↑/↓ scroll, Esc close) ");
+
+    let heading = |title: &str| {
+        Line::from(Span::styled(
+            format!(" {}", title),
+            Style::default().fg(app.theme.title).add_modifier(Modifier::BOLD),
+        ))
+    };
+    let entry = |keys: String, description: &str| {
+        Line::from(vec![
+            Span::styled(format!("   {:<width$}", keys, width = HELP_KEY_WIDTH), Style::default().fg(Color::Yellow)),
+            Span::raw(description.to_string()),
+        ])
+    };
+
+    let mut lines = vec![heading("Now Playing")];
+    for (actions, description) in HELP_ACTIONS {
+        let keys: Vec<String> = actions.iter().flat_map(|&action| app.keymap.keys_for(action)).collect();
+        if !keys.is_empty() {
+            lines.push(entry(keys.join(" "), description));
+        }
+    }
+    for (mode, keys) in HELP_MODES {
+        lines.push(Line::from(""));
+        lines.push(heading(mode));
+        lines.extend(keys.iter().map(|(keys, description)| entry(keys.to_string(), description)));
+    }
+
+    // 마지막 줄이 보이면 더 내려가지 않음
+    let visible = block.inner(area).height as usize;
+    let max_scroll = lines.len().saturating_sub(visible) as u16;
+    app.help_scroll = app.help_scroll.min(max_scroll);
+
+    let paragraph = Paragraph::new(lines).block(block).scroll((app.help_scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+/// Music.app 종료 안내 배너 (화면 중앙, 다시 실행 키는 현재 키맵 기준)
+fn render_music_banner(frame: &mut Frame, app: &App) {
+    let keys = app.keymap.keys_for(Action::RelaunchMusic);
+    let message = if keys.is_empty() {
+        " Music.app is not running ".to_string()
+    } else {
+        format!(" Music.app is not running — press {} to relaunch ", keys.join("/"))
+    };
+    let area = frame.area();
+    let width = (message.width() as u16 + 2).min(area.width);
+    let height = 3.min(area.height);
+    let banner = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+
+    frame.render_widget(Clear, banner);
+    let paragraph = Paragraph::new(message)
+        .style(Style::default().fg(Color::White).bg(Color::Red).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    frame.render_widget(paragraph, banner);
+}
+
+/// 세션 통계 렌더링 (화면 중앙 팝업)
+fn render_session_stats(frame: &mut Frame, app: &App) {
+    let area = popup_rect(50, 40, 36, 8, frame.area());
+    frame.render_widget(Clear, area);
+
+    let stats = &app.session_stats;
+    let listening = stats.listening_time as u64;
+    let top_artist = match stats.top_artist() {
+        Some((artist, count)) => format!("{} ({}x)", artist, count),
+        None => "-".to_string(),
+    };
+
+    let row = |label: &'static str, value: String| {
+        Line::from(vec![
+            Span::styled(label, Style::default().fg(Color::DarkGray)),
+            Span::styled(value, Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+        ])
+    };
+    let text = vec![
+        Line::from(""),
+        row("  Tracks played:  ", stats.tracks_played.to_string()),
+        row("  Skipped:        ", stats.skips.to_string()),
+        row("  Listening time: ", format!("{}h {:02}m", listening / 3600, listening % 3600 / 60)),
+        row("  Top artist:     ", top_artist),
+    ];
+
+    let popup = Paragraph::new(text)
+        .block(Block::default().borders(Borders::ALL).title(" Session Stats "));
+    frame.render_widget(popup, area);
+}
+
+/// 보관함 통계 렌더링 (처음 세는 동안은 안내 문구, 다시 세는 동안은 이전 값 유지)
+fn render_library_stats(frame: &mut Frame, app: &App) {
+    let area = popup_rect(50, 40, 36, 7, frame.area());
+    frame.render_widget(Clear, area);
+
+    let title = if app.library_stats_loading() { " Library Stats (counting…) " } else { " Library Stats " };
+    let block = Block::default().borders(Borders::ALL).title(title);
+
+    let Some(stats) = app.library_stats else {
+        let message = if app.library_stats_loading() { "  Counting library…" } else { "  Library stats unavailable" };
+        let empty = Paragraph::new(vec![Line::from(""), Line::from(message)])
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    };
+
+    let total = stats.total_time as u64;
+    let total_time = if total >= 86400 {
+        format!("{}d {}h {:02}m", total / 86400, total % 86400 / 3600, total % 3600 / 60)
+    } else {
+        format!("{}h {:02}m", total / 3600, total % 3600 / 60)
+    };
+
+    let row = |label: &'static str, value: String| {
+        Line::from(vec![
+            Span::styled(label, Style::default().fg(Color::DarkGray)),
+            Span::styled(value, Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+        ])
+    };
+    let text = vec![
+        Line::from(""),
+        row("  Tracks:     ", stats.tracks.to_string()),
+        row("  Total time: ", total_time),
+        row("  Playlists:  ", stats.playlists.to_string()),
+    ];
+
+    frame.render_widget(Paragraph::new(text).block(block), area);
+}
+
+/// 가사 렌더링 (화면 중앙 팝업)
+/// 타임스탬프가 있으면 현재 줄을 강조하고 가운데에 오도록 자동 스크롤
+fn render_lyrics(frame: &mut Frame, app: &App) {
+    let area = popup_rect(60, 70, 30, 6, frame.area());
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" Lyrics — {} ", app.track.name));
+
+    if app.lyrics.lines.is_empty() {
+        let message = if app.lyrics_loading { "  Loading lyrics…" } else { "  No lyrics available" };
+        let empty = Paragraph::new(message)
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let active = app.lyrics.active_line(app.display_position());
+    // 한 줄이 화면 한 행이 되도록 긴 줄은 줄바꿈 대신 잘라서 스크롤 위치가 줄 번호와 맞게 함
+    let text_width = area.width.saturating_sub(4) as usize;
+    let lines: Vec<Line> = app.lyrics.lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let style = match active {
+                Some(active) if active == i => Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                Some(_) => Style::default().fg(Color::DarkGray),
+                None => Style::default().fg(Color::White),
+            };
+            Line::from(Span::styled(format!("  {}", truncate_to_width(&line.text, text_width)), style))
+        })
+        .collect();
+
+    let scroll = match active {
+        Some(active) => active.saturating_sub(area.height.saturating_sub(2) as usize / 2) as u16,
+        None => app.lyrics_scroll,
+    };
+
+    let paragraph = Paragraph::new(lines).block(block).scroll((scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+/// 확인 팝업 렌더링 (화면 중앙)
+fn render_confirm(frame: &mut Frame, message: &str) {
+    let area = centered_rect(50, 20, frame.area());
+    let height = 5;
+    let y_pos = area.y + area.height.saturating_sub(height) / 2;
+    let popup_area = Rect::new(area.x, y_pos, area.width, height.min(frame.area().height));
+
+    frame.render_widget(Clear, popup_area);
+
+    let text = vec![
+        Line::from(Span::styled(message, Style::default().fg(Color::White).add_modifier(Modifier::BOLD))),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("y", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" Yes   "),
+            Span::styled("n", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::raw(" No"),
+        ]),
+    ];
+
+    let popup = Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Confirm ")
+                .border_style(Style::default().fg(Color::Red)),
+        );
+    frame.render_widget(popup, popup_area);
+}
+
+/// 검색 팝업 최소 폭 (테두리 포함)
+const SEARCH_POPUP_MIN_WIDTH: u16 = 30;
+/// 검색 입력창 높이 (테두리 + 입력 한 줄)
+const SEARCH_INPUT_HEIGHT: u16 = 3;
+/// 검색 결과 팝업 최소 높이 (테두리 + 결과 세 줄)
+const SEARCH_RESULTS_MIN_HEIGHT: u16 = 5;
+
+/// Helper: 최소 크기를 보장하는 중앙 팝업 Rect
+/// 비율로 계산한 크기가 최소 크기보다 작으면 늘리고,
+/// 화면 자체가 최소 크기보다 작으면 화면 전체를 사용
+fn popup_rect(percent_x: u16, percent_y: u16, min_width: u16, min_height: u16, r: Rect) -> Rect {
+    if r.width < min_width || r.height < min_height {
+        return r;
+    }
+
+    let area = centered_rect(percent_x, percent_y, r);
+    let width = area.width.max(min_width);
+    let height = area.height.max(min_height);
+    Rect::new(r.x + (r.width - width) / 2, r.y + (r.height - height) / 2, width, height)
 }
 
 /// Helper: 화면 중앙에 특정 크기의 Rect 생성
@@ -308,6 +1631,152 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
+/// 텍스트 진행 바 폭 (상태 요약용)
+const STATUS_BAR_WIDTH: usize = 20;
+
+/// 현재 재생 상태의 두 줄 텍스트 요약
+/// 1줄: 상태 아이콘 + 아티스트 - 제목, 2줄: 텍스트 진행 바 + 시간 + 볼륨
+pub fn status_text(track: &TrackInfo, volume: u8) -> String {
+    let icon = match track.state {
+        PlayerState::Playing => "▶",
+        PlayerState::Paused => "⏸",
+        PlayerState::Stopped => "⏹",
+    };
+
+    let first = if track.name.is_empty() {
+        format!("{} Stopped", icon)
+    } else {
+        format!("{} {} - {}", icon, track.artist, track.name)
+    };
+
+    let ratio = if track.duration > 0.0 {
+        (track.player_position / track.duration).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let filled = (ratio * STATUS_BAR_WIDTH as f64).round() as usize;
+    let second = format!(
+        "{}{} {}/{} {}%",
+        "━".repeat(filled),
+        "─".repeat(STATUS_BAR_WIDTH - filled),
+        format_time(track.player_position),
+        format_time(track.duration),
+        volume
+    );
+
+    format!("{}\n{}", first, second)
+}
+
+/// 마퀴에서 텍스트 끝과 다시 시작하는 처음 사이 간격
+const MARQUEE_GAP: &str = "   ";
+
+/// 너비를 넘는 텍스트를 `offset` 글자만큼 흘려서 너비에 맞게 자르기 (넘지 않으면 그대로)
+fn marquee(text: &str, width: usize, offset: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+
+    let looped: Vec<char> = text.chars().chain(MARQUEE_GAP.chars()).collect();
+    let mut output = String::new();
+    let mut used = 0;
+    for &c in looped.iter().cycle().skip(offset % looped.len()) {
+        let char_width = c.width().unwrap_or(0);
+        if used + char_width > width {
+            break;
+        }
+        output.push(c);
+        used += char_width;
+    }
+    output
+}
+
+/// 너비를 넘는 텍스트의 가운데를 `…`로 줄여 너비에 맞추기 (앞뒤 글자 유지)
+fn truncate_to_width(text: &str, max: usize) -> String {
+    if text.width() <= max {
+        return text.to_string();
+    }
+    if max == 0 {
+        return String::new();
+    }
+
+    let tail_width = (max - 1) / 2;
+    let head_width = max - 1 - tail_width;
+
+    let mut head = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if used + char_width > head_width {
+            break;
+        }
+        head.push(c);
+        used += char_width;
+    }
+
+    let mut tail = Vec::new();
+    let mut used = 0;
+    for c in text.chars().rev() {
+        let char_width = c.width().unwrap_or(0);
+        if used + char_width > tail_width {
+            break;
+        }
+        tail.push(c);
+        used += char_width;
+    }
+
+    format!("{}…{}", head, tail.iter().rev().collect::<String>())
+}
+
+/// 전체 너비 안에서 각 항목에 줄 너비 계산
+/// 몫보다 짧은 항목은 그대로 두고 남는 너비를 긴 항목들에 고르게 나눕니다.
+fn fit_widths(widths: &[usize], budget: usize) -> Vec<usize> {
+    let mut fitted = widths.to_vec();
+    if widths.iter().sum::<usize>() <= budget {
+        return fitted;
+    }
+
+    let mut remaining = budget;
+    let mut open: Vec<usize> = (0..widths.len()).collect();
+    loop {
+        let share = remaining / open.len().max(1);
+        let (fits, long): (Vec<usize>, Vec<usize>) = open.iter().partition(|&&i| widths[i] <= share);
+        if fits.is_empty() {
+            // 남은 항목은 몫만큼 (나머지는 앞 항목부터 1씩)
+            for (n, &i) in open.iter().enumerate() {
+                fitted[i] = share + usize::from(n < remaining % open.len().max(1));
+            }
+            return fitted;
+        }
+        remaining -= fits.iter().map(|&i| widths[i]).sum::<usize>();
+        open = long;
+    }
+}
+
+/// 장르와 발매 연도 텍스트 (예: "Rock • 2019", 둘 다 없으면 None)
+fn genre_text(track: &TrackInfo) -> Option<String> {
+    let mut parts = Vec::new();
+    if !track.genre.is_empty() {
+        parts.push(track.genre.clone());
+    }
+    if track.year > 0 {
+        parts.push(track.year.to_string());
+    }
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(" • "))
+    }
+}
+
+/// 앨범 이름 뒤에 발매 연도 붙이기 (예: "Album (2019)")
+fn album_with_year(album: &str, year: u32) -> String {
+    if year > 0 {
+        format!("{} ({})", album, year)
+    } else {
+        album.to_string()
+    }
+}
+
 /// 초를 mm:ss 형식으로 변환
 fn format_time(seconds: f64) -> String {
     let total_secs = seconds as u64;